@@ -1,6 +1,11 @@
 use genkei::{
-    Color, ColorTrait, DefaultStyleOptions, HtmlAttribute, MarginTrait, PaddingTrait, Renderer,
-    Style, StyleBuilder, StyleRenderer, Styleable, TextContent,
+    Animation, AnimationTrait, AspectRatioTrait, BackdropFilterTrait, BackgroundTrait, BorderTrait,
+    Breakpoint, Children, ClassNaming, Color, ColorStyle, ColorSyntax, ColorTrait, ComplexColor,
+    ContainerQuery, ContainerSize, ContainerTrait, ContentTrait, DarkMode, DefaultStyleOptions,
+    FilterTrait, FlexTrait, FontFamily, FontSize, FontTrait, GradientDirection, HeightTrait,
+    HtmlAttribute, Margin, MarginTrait, MediaQuery, OpacityTrait, OutlineTrait, PaddingTrait,
+    Palette, Renderer, StateVariant, Style, StyleBuilder, StyleError, StyleOptions, StyleRenderer,
+    Styleable, TextContent, TransformTrait, TransitionTrait, WidthTrait,
 };
 
 #[test]
@@ -26,6 +31,14 @@ fn test_style_to_css() {
     assert_eq!(css, ".p-1{padding:0.25rem}");
 }
 
+#[test]
+fn test_style_text_align_into_style() {
+    assert_eq!(
+        Style::from(genkei::TextAlign::Center),
+        Style::TextAlign(genkei::TextAlign::Center)
+    );
+}
+
 #[test]
 fn test_style_render_basic() {
     let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
@@ -58,7 +71,7 @@ fn test_style_media_query() {
     renderer.include_styles(
         StyleBuilder::new()
             .p(1)
-            .mq(genkei::MediaQuery::Lg, |style| style.p(2))
+            .mq(genkei::Breakpoint::Lg, |style| style.p(2))
             .build(),
     );
     assert_eq!(
@@ -121,6 +134,34 @@ fn test_renderer_css() {
         .ends_with(".p-1{padding:0.25rem}.bg-slate-50{background-color:rgb(248,250,252)}"));
 }
 
+#[test]
+fn test_renderer_with_options() {
+    let div = genkei::div().id("id").bg_color(Color::Slate050).p(1);
+
+    let mut renderer = Renderer::with_options(TestOptions {
+        class_naming: ClassNaming::Minified,
+        ..Default::default()
+    });
+    renderer.push_tag(div.clone());
+    let mut result = renderer.render().unwrap();
+    assert_eq!(result.html(), "<div class=\"a b\" id=id></div>");
+    let stylesheet = result.stylesheet("app");
+    assert!(stylesheet
+        .css()
+        .ends_with(".a{padding:0.25rem}.b{background-color:rgb(248,250,252)}"));
+
+    let mut result = Renderer::render_tag_with_options(
+        genkei::div().border(2),
+        TestOptions {
+            class_naming: ClassNaming::Tailwind,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(result.html(), "<div class=border-2></div>");
+    assert!(result.css().ends_with(".border-2{border-width:2px}"));
+}
+
 #[test]
 fn test_usage_example() {
     let tag = genkei::div()
@@ -135,3 +176,1622 @@ fn test_usage_example() {
         "<div class=\"p-2 bg-slate-500\" id=id>Hello, world!</div>"
     );
 }
+
+#[test]
+fn test_style_opacity() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(StyleBuilder::new().opacity(50).build());
+    assert_eq!(renderer.render().unwrap().0, ".opacity-50{opacity:0.5}");
+}
+
+#[test]
+fn test_style_transform_compose() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .translate_x(2)
+            .active(|style| style.scale(95))
+            .build(),
+    );
+    let transform = "transform:translate3d(var(--tw-translate-x,0),var(--tw-translate-y,0),var(--tw-translate-z,0)) rotateX(var(--tw-rotate-x,0)) rotateY(var(--tw-rotate-y,0)) rotate(var(--tw-rotate,0)) skewX(var(--tw-skew-x,0)) skewY(var(--tw-skew-y,0)) scaleX(var(--tw-scale-x,1)) scaleY(var(--tw-scale-y,1))";
+    assert_eq!(
+        renderer.render().unwrap().0,
+        format!(
+            ".translate-x-2{{--tw-translate-x:0.5rem;{transform}}}.active\\:scale-95:active{{--tw-scale-x:0.95;--tw-scale-y:0.95;{transform}}}"
+        )
+    );
+}
+
+#[test]
+fn test_style_transition() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .transition_colors()
+            .duration(300)
+            .ease_out()
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".transition-colors{transition-property:color,background-color,border-color,outline-color,text-decoration-color,fill,stroke;transition-timing-function:cubic-bezier(0.4,0,0.2,1);transition-duration:150ms}.duration-300{transition-duration:300ms}.ease-out{transition-timing-function:cubic-bezier(0,0,0.2,1)}"
+    );
+}
+
+#[test]
+fn test_style_animation_keyframes_once() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .animate_spin()
+            .hover(|style| style.animate_spin())
+            .mq(genkei::Breakpoint::Md, |style| {
+                style.animate(Animation::custom(
+                    "fade",
+                    "1s ease-in",
+                    "from{opacity:0}to{opacity:1}",
+                ))
+            })
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        "@keyframes fade{from{opacity:0}to{opacity:1}}@keyframes spin{to{transform:rotate(360deg)}}.animate-spin{animation:spin 1s linear infinite}.hover\\:animate-spin:hover{animation:spin 1s linear infinite}@media(min-width:768px){.md\\:animate-fade{animation:fade 1s ease-in}}"
+    );
+}
+
+#[test]
+fn test_style_animation_custom_keyframes_checked() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_style(Animation::custom("fade", "1s", "from{opacity:0}"));
+    renderer.include_style(Animation::custom("fade", "2s", "to{opacity:0}"));
+    assert_eq!(
+        renderer.render(),
+        Err(StyleError::InvalidKeyframes(
+            "fade".into(),
+            "the name is used with different frames"
+        ))
+    );
+
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    assert!(renderer
+        .use_as_classname(std::collections::BTreeSet::from([Style::Animation(
+            Animation::custom("fade in", "1s", "from{opacity:0}")
+        )]))
+        .is_err());
+    renderer.include_style(Animation::custom("fade", "1s", "}.x{color:red"));
+    assert!(renderer.render().is_err());
+}
+
+#[test]
+fn test_style_selector_from_escaped_classname() {
+    let div = genkei::div()
+        .w_percent(1, 2)
+        .hover(|style| style.w_percent(1, 3));
+    let mut result = Renderer::render_tag(div).unwrap();
+    assert_eq!(result.html(), "<div class=\"w-1/2 hover:w-1/3\"></div>");
+    assert!(result
+        .css()
+        .ends_with(".w-1\\/2{width:50%}.hover\\:w-1\\/3:hover{width:33.333336%}"));
+}
+
+#[test]
+fn test_style_media_query_breakpoint() {
+    assert_eq!(
+        genkei::MediaQuery::Min(genkei::Breakpoint::Xxl).breakpoint(),
+        Some(genkei::Breakpoint::Xxl)
+    );
+    assert_eq!(genkei::Breakpoint::Xxxl.pixels(), 1920);
+
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .mq(genkei::Breakpoint::Sm, |style| style.p(1))
+            .mq(genkei::Breakpoint::Xxxl, |style| style.p(2))
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        "@media(min-width:640px){.sm\\:p-1{padding:0.25rem}}@media(min-width:1920px){.xxxl\\:p-2{padding:0.5rem}}"
+    );
+}
+
+#[test]
+fn test_style_max_width_and_aspect_ratio() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .max_w_container(genkei::ContainerSize::Xxxxxxxl)
+            .max_w_screen(genkei::Breakpoint::Lg)
+            .max_h_screen()
+            .aspect_ratio(4, 3)
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".max-w-7xl{max-width:80rem}.max-w-screen-lg{max-width:1024px}.max-h-screen{max-height:100vh}.aspect-4\\/3{aspect-ratio:4/3}"
+    );
+}
+
+#[test]
+fn test_style_selector_escaping() {
+    let div = genkei::div()
+        .w_percent(1, 2)
+        .hover(|style| style.aspect_ratio(16, 9));
+    let mut result = Renderer::render_tag(div).unwrap();
+    assert_eq!(
+        result.html(),
+        "<div class=\"w-1/2 hover:aspect-16/9\"></div>"
+    );
+    assert!(result
+        .css()
+        .ends_with(".w-1\\/2{width:50%}.hover\\:aspect-16\\/9:hover{aspect-ratio:16/9}"));
+}
+
+#[test]
+fn test_style_transparent_colors() {
+    use genkei::BorderTrait;
+
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .bg_color(Color::Transparent)
+            .fg_color(Color::Transparent)
+            .border_color(Color::Transparent)
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".fg-transparent{color:rgba(0,0,0,0)}.bg-transparent{background-color:rgba(0,0,0,0)}.bc-transparent{border-color:rgba(0,0,0,0)}"
+    );
+}
+
+#[test]
+fn test_style_background_gradient() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .bg_gradient(GradientDirection::ToRight)
+            .gradient_from(Color::rgb(255, 0, 0))
+            .gradient_to(Color::Transparent)
+            .bg_clip_text()
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".bg-gradient-to-r{background-image:linear-gradient(to right,var(--tw-gradient-stops))}.from-rgb-255-0-0{--tw-gradient-from:rgb(255,0,0);--tw-gradient-to:rgba(255,0,0,0);--tw-gradient-stops:var(--tw-gradient-from),var(--tw-gradient-to)}.to-transparent{--tw-gradient-to:rgba(0,0,0,0)}.bg-clip-text{-webkit-background-clip:text;background-clip:text}"
+    );
+
+    let css = StyleRenderer::<DefaultStyleOptions>::to_css(genkei::Background::Via(
+        ComplexColor::Custom("var(--brand)".into()).into(),
+    ))
+    .unwrap();
+    assert!(css.ends_with(
+        "{--tw-gradient-to:color-mix(in oklab,var(--brand) 0%,rgba(0,0,0,0));--tw-gradient-stops:var(--tw-gradient-from),var(--brand),var(--tw-gradient-to)}"
+    ));
+}
+
+#[test]
+fn test_style_background_image() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .bg_image("/img/hero.png")
+            .bg_cover()
+            .bg_center()
+            .bg_no_repeat()
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".bg-\\[url\\(\\/img\\/hero\\.png\\)\\]{background-image:url(\"/img/hero.png\")}.bg-cover{background-size:cover}.bg-center{background-position:center}.bg-no-repeat{background-repeat:no-repeat}"
+    );
+}
+
+#[test]
+fn test_style_background_image_escaped() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    let spaced = renderer
+        .use_as_classname(StyleBuilder::new().bg_image("a b").build())
+        .unwrap();
+    let underscored = renderer
+        .use_as_classname(StyleBuilder::new().bg_image("a_b").build())
+        .unwrap();
+    assert_eq!(spaced, "bg-[url(a_b)]");
+    assert_eq!(underscored, "bg-[url(a\\_b)]");
+
+    let css = StyleRenderer::<DefaultStyleOptions>::to_css(genkei::Background::Image(
+        "</style>\"".into(),
+    ))
+    .unwrap();
+    assert!(css.ends_with("{background-image:url(\"\\3c /style>\\\"\")}"));
+}
+
+#[test]
+fn test_style_filter_compose() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .blur(4)
+            .hover(|style| style.grayscale(100))
+            .backdrop_filter_blur(8)
+            .build(),
+    );
+    let filter = "var(--tw-blur,) var(--tw-brightness,) var(--tw-contrast,) var(--tw-grayscale,) var(--tw-hue-rotate,) var(--tw-invert,) var(--tw-saturate,) var(--tw-sepia,) var(--tw-drop-shadow,)";
+    let backdrop_filter = "var(--tw-backdrop-blur,) var(--tw-backdrop-brightness,) var(--tw-backdrop-contrast,) var(--tw-backdrop-grayscale,) var(--tw-backdrop-hue-rotate,) var(--tw-backdrop-invert,) var(--tw-backdrop-saturate,) var(--tw-backdrop-sepia,) var(--tw-backdrop-drop-shadow,)";
+    assert_eq!(
+        renderer.render().unwrap().0,
+        format!(
+            ".blur-4{{--tw-blur:blur(4px);filter:{filter}}}.bf-blur-8{{--tw-backdrop-blur:blur(8px);-webkit-backdrop-filter:{backdrop_filter};backdrop-filter:{backdrop_filter}}}.hover\\:grayscale-100:hover{{--tw-grayscale:grayscale(100%);filter:{filter}}}"
+        )
+    );
+}
+
+#[test]
+fn test_style_dark_media() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .dark(|style| style.hover(|style| style.bg_color(Color::Slate900)))
+            .mq(genkei::Breakpoint::Lg, |style| {
+                style.dark(|style| style.p(2))
+            })
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        "@media(min-width:1024px){@media(prefers-color-scheme:dark){.lg\\:dark\\:p-2{padding:0.5rem}}}@media(prefers-color-scheme:dark){.dark\\:hover\\:bg-slate-900:hover{background-color:rgb(15,23,42)}}"
+    );
+}
+
+/// Style options for the tests, every field switches one option away from the default.
+#[derive(Debug, Clone, Default)]
+struct TestOptions {
+    dark_mode: DarkMode,
+    /// Overrides the md breakpoint, the xs font size, the sans font family and the radius.
+    theme: bool,
+    color_syntax: ColorSyntax,
+    display_p3_fallback: bool,
+    class_naming: ClassNaming,
+    readable_css: bool,
+    design_tokens: bool,
+    palette: Palette,
+}
+
+impl StyleOptions for TestOptions {
+    fn spacing<Stream>(&self, stream: &mut Stream, value: i32) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        DefaultStyleOptions.spacing(stream, value)
+    }
+
+    fn percentage<Stream>(&self, stream: &mut Stream, x: i32, y: i32) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        DefaultStyleOptions.percentage(stream, x, y)
+    }
+
+    fn border<Stream>(&self, stream: &mut Stream, value: i32) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        DefaultStyleOptions.border(stream, value)
+    }
+
+    fn font_size<Stream>(&self, stream: &mut Stream, value: i32) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        DefaultStyleOptions.font_size(stream, value)
+    }
+
+    fn breakpoint(&self, breakpoint: Breakpoint) -> i32 {
+        match breakpoint {
+            Breakpoint::Md if self.theme => 900,
+            x => x.pixels(),
+        }
+    }
+
+    fn font_size_scale<Stream>(
+        &self,
+        stream: &mut Stream,
+        size: &FontSize,
+    ) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        match size {
+            FontSize::Xs if self.theme => write!(stream, "font-size:11px;line-height:16px")?,
+            x => DefaultStyleOptions.font_size_scale(stream, x)?,
+        }
+        Ok(())
+    }
+
+    fn font_family<Stream>(
+        &self,
+        stream: &mut Stream,
+        family: &FontFamily,
+    ) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        match family {
+            FontFamily::Sans if self.theme => write!(stream, "Inter,sans-serif")?,
+            x => DefaultStyleOptions.font_family(stream, x)?,
+        }
+        Ok(())
+    }
+
+    fn radius<Stream>(&self, stream: &mut Stream, value: i32) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        if self.theme {
+            write!(stream, "{}px", value * 2)?;
+            Ok(())
+        } else {
+            DefaultStyleOptions.radius(stream, value)
+        }
+    }
+
+    fn dark_mode(&self) -> DarkMode {
+        self.dark_mode
+    }
+
+    fn design_tokens(&self) -> bool {
+        self.design_tokens
+    }
+
+    fn color_syntax(&self) -> ColorSyntax {
+        self.color_syntax
+    }
+
+    fn display_p3_fallback(&self) -> bool {
+        self.display_p3_fallback
+    }
+
+    fn class_naming(&self) -> ClassNaming {
+        self.class_naming
+    }
+
+    fn readable_css(&self) -> bool {
+        self.readable_css
+    }
+
+    fn palette(&self) -> &Palette {
+        &self.palette
+    }
+}
+
+fn test_renderer(options: TestOptions) -> StyleRenderer<TestOptions> {
+    StyleRenderer::with_options(options, false, 4096)
+}
+
+#[test]
+fn test_style_dark_class() {
+    let mut renderer = test_renderer(TestOptions {
+        dark_mode: DarkMode::Class,
+        ..Default::default()
+    });
+    renderer.include_styles(
+        StyleBuilder::new()
+            .dark(|style| style.hover(|style| style.bg_color(Color::Slate900)))
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".dark .dark\\:hover\\:bg-slate-900:hover{background-color:rgb(15,23,42)}"
+    );
+
+    // the dark ancestor comes before the peer sibling
+    let mut renderer = test_renderer(TestOptions {
+        dark_mode: DarkMode::Class,
+        ..Default::default()
+    });
+    renderer.include_styles(
+        StyleBuilder::new()
+            .peer_checked(|style| style.dark(|style| style.p(2)))
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".dark .peer:checked~.peer-checked\\:dark\\:p-2{padding:0.5rem}"
+    );
+}
+
+#[test]
+fn test_style_state_variants() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .odd(|style| style.bg_color(Color::Slate100))
+            .hover(|style| style.on_disabled(|style| style.opacity(50)))
+            .on_placeholder(|style| style.fg_color(Color::Slate400))
+            .focus_within(|style| style.p(2))
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".placeholder\\:fg-slate-400::placeholder{color:rgb(148,163,184)}.focus-within\\:p-2:focus-within{padding:0.5rem}.disabled\\:hover\\:opacity-50:disabled:hover{opacity:0.5}.odd\\:bg-slate-100:nth-child(odd){background-color:rgb(241,245,249)}"
+    );
+}
+
+#[test]
+fn test_style_pseudo_element_content() {
+    let div = genkei::div()
+        .after(|style| style.content("*").pl(1))
+        .before(|style| style.content("a \"b_c\" </style>"));
+    let mut result = Renderer::render_tag(div).unwrap();
+    assert_eq!(
+        result.html(),
+        "<div class=\"after:pl-1 after:content-[&apos;*&apos;] before:content-[&apos;a_&quot;b\\_c&quot;_&lt;/style&gt;&apos;]\"></div>"
+    );
+    assert!(result.css().ends_with(
+        ".after\\:pl-1::after{content:var(--tw-content,'');padding-left:0.25rem}.after\\:content-\\[\\'\\*\\'\\]::after{--tw-content:\"*\";content:var(--tw-content,'')}.before\\:content-\\[\\'a_\\\"b\\\\_c\\\"_\\<\\/style\\>\\'\\]::before{--tw-content:\"a \\\"b_c\\\" \\3c /style>\";content:var(--tw-content,'')}"
+    ));
+}
+
+#[test]
+fn test_style_nested_pseudo_elements_rejected() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(StyleBuilder::new().before(|x| x.after(|x| x.p(1))).build());
+    assert!(matches!(
+        renderer.render(),
+        Err(StyleError::CssSelectorUnsupported(_))
+    ));
+
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .on_placeholder(|x| x.before(|x| x.p(1)))
+            .build(),
+    );
+    assert!(matches!(
+        renderer.render(),
+        Err(StyleError::CssSelectorUnsupported(_))
+    ));
+
+    // pseudo-classes go before the pseudo-element
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(StyleBuilder::new().before(|x| x.hover(|x| x.p(1))).build());
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".hover\\:before\\:p-1:hover::before{content:var(--tw-content,'');padding:0.25rem}"
+    );
+}
+
+#[test]
+fn test_style_group_and_peer() {
+    let card = genkei::div()
+        .group()
+        .group_named("item")
+        .child(genkei::div().group_named_state("item", StateVariant::Hover, |style| style.p(2)))
+        .child(genkei::div().peer())
+        .child(genkei::div().peer_checked(|style| style.group_hover(|style| style.m(1))));
+    let mut result = Renderer::render_tag(card).unwrap();
+    assert_eq!(
+        result.html(),
+        "<div class=\"group group/item\"><div class=group-hover/item:p-2></div><div class=peer></div><div class=peer-checked:group-hover:m-1></div></div>"
+    );
+    assert!(result.css().ends_with(
+        ".group\\/item:hover .group-hover\\/item\\:p-2{padding:0.5rem}.group:hover .peer:checked~.peer-checked\\:group-hover\\:m-1{margin:0.25rem}"
+    ));
+}
+
+#[test]
+fn test_style_group_and_peer_rejected() {
+    let div = genkei::div().peer_state(StateVariant::Placeholder, |style| style.p(1));
+    assert!(matches!(
+        Renderer::render_tag(div),
+        Err(genkei::RenderError::Style(
+            StyleError::CssSelectorUnsupported(_)
+        ))
+    ));
+
+    let div = genkei::div().group_named("card item");
+    assert_eq!(
+        Renderer::render_tag(div).unwrap_err().to_string(),
+        "Style error: invalid name \"card item\": a marker name may only contain letters, digits, `-` and `_`"
+    );
+
+    let div = genkei::div().group_named_state("a}b", StateVariant::Hover, |style| style.p(1));
+    assert!(Renderer::render_tag(div).is_err());
+}
+
+#[test]
+fn test_style_media_query_variants() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .mq_range(Breakpoint::Md, Breakpoint::Lg, |style| style.p(2))
+            .mq(MediaQuery::Print, |style| style.m(0))
+            .mq(MediaQuery::MotionReduce, |style| style.animate_none())
+            .mq(MediaQuery::Raw("(min-width: 900px)".into()), |style| {
+                style.p(4)
+            })
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        "@media(min-width:768px){@media not all and (min-width:1024px){.md\\:max-lg\\:p-2{padding:0.5rem}}}@media print{.print\\:m-0{margin:0rem}}@media(prefers-reduced-motion:reduce){.motion-reduce\\:animate-none{animation:none}}@media (min-width: 900px){.\\[\\@media_\\(min-width\\:_900px\\)\\]\\:p-4{padding:1rem}}"
+    );
+}
+
+#[test]
+fn test_style_raw_media_query_rejected() {
+    let div = genkei::div().mq(MediaQuery::Raw("print{}body{color:red}".into()), |style| {
+        style.p(4)
+    });
+    assert_eq!(
+        Renderer::render_tag(div).unwrap_err().to_string(),
+        "Style error: invalid media query \"print{}body{color:red}\": braces, `;` and `</` would end the media query"
+    );
+}
+
+#[test]
+fn test_style_container_query() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .container_named("sidebar")
+            .cq(ContainerSize::Md, |style| style.p(2))
+            .cq(
+                ContainerQuery::Named("sidebar".into(), ContainerSize::Xxl),
+                |style| style.hover(|style| style.p(4)),
+            )
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".\\@container\\/sidebar{container-type:inline-size;container-name:sidebar}@container (min-width:28rem){.\\@md\\:p-2{padding:0.5rem}}@container sidebar (min-width:42rem){.\\@2xl\\/sidebar\\:hover\\:p-4:hover{padding:1rem}}"
+    );
+
+    let div = genkei::div().container_named("side bar");
+    assert_eq!(
+        Renderer::render_tag(div).unwrap_err().to_string(),
+        "Style error: invalid name \"side bar\": a container name must be a css identifier other than `none`, `and`, `not` and `or`"
+    );
+
+    let div = genkei::div().cq(
+        ContainerQuery::Named("none".into(), ContainerSize::Md),
+        |style| style.p(2),
+    );
+    assert!(matches!(
+        Renderer::render_tag(div),
+        Err(genkei::RenderError::Style(StyleError::InvalidName(_, _)))
+    ));
+}
+
+#[test]
+fn test_style_theme_options() {
+    let mut renderer = test_renderer(TestOptions {
+        theme: true,
+        ..Default::default()
+    });
+    renderer.include_styles(
+        StyleBuilder::new()
+            .font_size(FontSize::Xs)
+            .font_family(FontFamily::Sans)
+            .border_radius(3)
+            .mq(Breakpoint::Md, |style| style.font_size(FontSize::Base))
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".text-xs{font-size:11px;line-height:16px}.ff-sans{font-family:Inter,sans-serif}.br-3{border-radius:6px}@media(min-width:900px){.md\\:text-base{font-size:1rem;line-height:1.5rem}}"
+    );
+}
+
+#[test]
+fn test_style_design_tokens() {
+    let mut renderer = test_renderer(TestOptions {
+        design_tokens: true,
+        ..Default::default()
+    });
+    renderer.include_styles(
+        StyleBuilder::new()
+            .bg_color(Color::Slate500)
+            .fg_color(ComplexColor::var("brand"))
+            .p(4)
+            .m(13)
+            .build(),
+    );
+    // only the referenced tokens are defined
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ":root{--color-slate-500:rgb(100,116,139);--spacing-1:0.25rem;--spacing-4:1rem}.p-4{padding:var(--spacing-4)}.m-13{margin:calc(var(--spacing-1)*13)}.bg-slate-500{background-color:var(--color-slate-500)}.fg-var-brand{color:var(--brand)}"
+    );
+    let renderer = test_renderer(TestOptions {
+        design_tokens: true,
+        ..Default::default()
+    });
+    assert_eq!(renderer.render().unwrap().0, "");
+}
+
+#[test]
+fn test_style_design_tokens_with_options() {
+    let mut renderer = test_renderer(TestOptions {
+        design_tokens: true,
+        class_naming: ClassNaming::Tailwind,
+        ..Default::default()
+    });
+    let classnames = renderer
+        .use_as_classname(
+            StyleBuilder::new()
+                .border_radius(2)
+                .bg_color(Color::Slate500)
+                .build(),
+        )
+        .unwrap();
+    assert_eq!(classnames, "bg-slate-500 rounded-lg");
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ":root{--color-slate-500:rgb(100,116,139)}.bg-slate-500{background-color:var(--color-slate-500)}.rounded-lg{border-radius:0.5rem}"
+    );
+}
+
+#[test]
+fn test_style_custom_palette() {
+    let mut palette = Palette::new();
+    let brand = palette
+        .register("brand-500", Color::Rgb(12, 34, 56))
+        .unwrap();
+    assert_eq!(palette.parse("brand-500"), Ok(brand));
+    assert_eq!(
+        palette.parse("brand-600"),
+        Err(StyleError::InvalidSimpleColor("brand-600".into()))
+    );
+    assert_eq!("brand-500".parse::<Color>(), Ok(brand));
+    assert_eq!(brand.to_classname(), "brand-500");
+
+    let mut renderer = test_renderer(TestOptions {
+        palette: palette.clone(),
+        ..Default::default()
+    });
+    renderer.include_styles(
+        StyleBuilder::new()
+            .bg_color(brand)
+            .border_color(palette.parse("brand-500").unwrap())
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".bg-brand-500{background-color:rgb(12,34,56)}.bc-brand-500{border-color:rgb(12,34,56)}"
+    );
+
+    // the names resolve per renderer
+    let mut other = Palette::new();
+    other.register("brand-500", Color::White).unwrap();
+    let mut renderer = test_renderer(TestOptions {
+        palette: other,
+        ..Default::default()
+    });
+    renderer.include_styles(StyleBuilder::new().bg_color(brand).build());
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".bg-brand-500{background-color:rgb(255,255,255)}"
+    );
+    assert_eq!(
+        StyleRenderer::<DefaultStyleOptions>::to_css(ColorStyle::Background(brand)),
+        Err(StyleError::InvalidSimpleColor("brand-500".into()))
+    );
+
+    // names are only shared as handles, the values stay in the palette
+    let missing = Palette::new().register("missing", Color::White).unwrap();
+    assert_eq!(
+        palette.register("alias", missing),
+        Err(StyleError::InvalidSimpleColor("missing".into()))
+    );
+    assert_eq!(
+        palette.parse("missing"),
+        Err(StyleError::InvalidSimpleColor("missing".into()))
+    );
+    let alias = palette.register("alias", brand).unwrap();
+    assert_eq!(palette.resolve(&alias), Some(Color::Rgb(12, 34, 56)));
+    assert_eq!(
+        palette.register(&format!("tenant-{}", 7), Color::Black),
+        Ok("tenant-7".parse().unwrap())
+    );
+    assert_eq!(
+        palette.register("brand 500", Color::Black),
+        Err(StyleError::InvalidName(
+            "brand 500".into(),
+            "a palette color name may only contain letters, digits, `-` and `_`"
+        ))
+    );
+
+    // the color math needs the value of the palette
+    assert_eq!(
+        brand.to_srgb(),
+        Err(StyleError::InvalidSimpleColor("brand-500".into()))
+    );
+    assert_eq!(
+        brand.to_css_value(),
+        Err(StyleError::InvalidSimpleColor("brand-500".into()))
+    );
+    assert!(brand.contrast_ratio(&Color::White).is_err());
+    assert_eq!(
+        palette
+            .resolve(&brand)
+            .unwrap()
+            .contrast_ratio(&Color::White),
+        Color::Rgb(12, 34, 56).contrast_ratio(&Color::White)
+    );
+}
+
+#[test]
+fn test_color_parse_css_syntax() {
+    let parse = |s: &str| s.parse::<Color>();
+    assert_eq!(parse("#0ea5e9"), Ok(Color::Rgb(14, 165, 233)));
+    assert_eq!(parse("#FFF"), Ok(Color::Rgb(255, 255, 255)));
+    assert_eq!(
+        parse("#fff8"),
+        Ok(Color::rgba(255, 255, 255, 136.0 / 255.0))
+    );
+    assert_eq!(parse("rgb(1 2 3 / 50%)"), Ok(Color::rgba(1, 2, 3, 0.5)));
+    assert_eq!(parse("rgba(1, 2, 3, 0.25)"), Ok(Color::rgba(1, 2, 3, 0.25)));
+    assert_eq!(parse("rgb(100% 0% 0%)"), Ok(Color::Rgb(255, 0, 0)));
+    assert_eq!(
+        parse("hsl(0.5turn 100% 50%)"),
+        Ok(Color::Hsl(180.0.into(), 100.0.into(), 50.0.into()))
+    );
+    assert_eq!(
+        parse("hsl(120 100% 25% / 50%)"),
+        Ok(Color::rgba(0, 128, 0, 0.5))
+    );
+    assert_eq!(parse("RebeccaPurple"), Ok(Color::Rgb(102, 51, 153)));
+    assert_eq!(parse("slate-500"), Ok(Color::Slate500));
+
+    assert_eq!(
+        parse("#12345"),
+        Err(StyleError::InvalidColor(
+            "#12345".into(),
+            "expected 3, 4, 6 or 8 hex digits"
+        ))
+    );
+    assert_eq!(
+        parse("rgb(1 2)"),
+        Err(StyleError::InvalidColor(
+            "rgb(1 2)".into(),
+            "expected 3 components"
+        ))
+    );
+    assert_eq!(
+        parse("lab(50% 0 0)"),
+        Err(StyleError::InvalidColor(
+            "lab(50% 0 0)".into(),
+            "unsupported color function"
+        ))
+    );
+    assert_eq!(
+        parse("notacolor"),
+        Err(StyleError::InvalidSimpleColor("notacolor".into()))
+    );
+}
+
+#[test]
+fn test_color_math() {
+    let hsl = Color::Hsl(210.0.into(), 50.0.into(), 40.0.into());
+    assert_eq!(hsl.to_rgb(), Color::Rgb(51, 102, 153));
+    assert_eq!(hsl.alpha(0.5), Ok(Color::rgba(51, 102, 153, 0.5)));
+    assert_eq!(Color::Rgb(51, 102, 153).to_hsl(), Ok(hsl));
+
+    let (h, w, b, _) = Color::Rgb(255, 0, 0).to_hwba().unwrap();
+    assert_eq!((h, w, b), (0.0, 0.0, 0.0));
+    assert_eq!(
+        Color::from_hwba(0.0, 50.0, 0.0, 1.0),
+        Color::Rgb(255, 128, 128)
+    );
+
+    assert_eq!(
+        hsl.lighten(10.0),
+        Ok(Color::Hsl(210.0.into(), 50.0.into(), 50.0.into()))
+    );
+    assert_eq!(
+        Color::White.darken(100.0).unwrap().to_rgb(),
+        Color::Rgb(0, 0, 0)
+    );
+    assert_eq!(
+        Color::Gray500.saturate(-100.0).unwrap().to_rgb(),
+        Color::Rgb(117, 117, 117)
+    );
+    assert_eq!(
+        Color::Black.mix(Color::White, 0.5),
+        Ok(Color::Rgb(128, 128, 128))
+    );
+
+    assert_eq!(Color::White.contrast_ratio(&Color::Black), Ok(21.0));
+    assert_eq!(Color::Slate900.meets_aaa(&Color::White, false), Ok(true));
+    assert_eq!(Color::Slate400.meets_aa(&Color::White, false), Ok(false));
+    assert_eq!(Color::Slate500.meets_aa(&Color::White, true), Ok(true));
+    assert_eq!(Color::readable_on(&Color::Sky500), Ok(Color::Black));
+    assert_eq!(Color::readable_on(&Color::Slate800), Ok(Color::White));
+}
+
+#[test]
+fn test_color_oklch() {
+    let (l, c, h, _) = Color::Rgb(255, 0, 0).to_oklch().unwrap();
+    assert_eq!((l * 1000.0).round(), 628.0);
+    assert_eq!((c * 1000.0).round(), 258.0);
+    assert_eq!(h.round(), 29.0);
+    assert_eq!(
+        Color::from_oklch(l, c, h, 1.0).to_rgb(),
+        Color::Rgb(255, 0, 0)
+    );
+    assert_eq!(
+        "oklch(62.8% 0.2577 29.23)"
+            .parse::<Color>()
+            .unwrap()
+            .to_rgb(),
+        Color::Rgb(255, 0, 0)
+    );
+    assert_eq!(
+        Color::Sky500.to_css_value_with(ColorSyntax::Hex),
+        Ok("#0ea5e9".into())
+    );
+    assert_eq!(
+        Color::rgba(255, 0, 0, 0.5).to_css_value_with(ColorSyntax::Oklch),
+        Ok("oklch(0.628 0.2577 29.2339 / 0.5)".into())
+    );
+
+    let wide = Color::oklch(0.7, 0.3, 145.0);
+    assert!(!wide.in_srgb_gamut());
+    assert!(Color::Red500.in_srgb_gamut());
+
+    let mut renderer = test_renderer(TestOptions {
+        color_syntax: ColorSyntax::Hex,
+        display_p3_fallback: true,
+        ..Default::default()
+    });
+    renderer.include_styles(
+        StyleBuilder::new()
+            .bg_color(Color::Red500)
+            .fg_color(wide)
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".fg-oklch-0\\.7-0\\.3-145{color:#00c800}\
+         @supports(color:color(display-p3 0 0 0)){\
+         .fg-oklch-0\\.7-0\\.3-145{color:color(display-p3 0.101 0.7712 0)}}\
+         .bg-red-500{background-color:#ef4444}"
+    );
+}
+
+#[test]
+fn test_color_shades() {
+    let mut palette = Palette::new();
+    let shades = palette
+        .register_shades("tenant", Color::Rgb(59, 130, 246))
+        .unwrap();
+    assert_eq!(shades[0].to_classname(), "tenant-50");
+    assert_eq!("tenant-950".parse(), Ok(shades[10]));
+    assert_eq!(palette.resolve(&shades[5]), Some(Color::Rgb(59, 130, 246)));
+    let shades = shades.map(|x| palette.resolve(&x).unwrap());
+    for pair in shades.windows(2) {
+        assert!(pair[0].relative_luminance().unwrap() > pair[1].relative_luminance().unwrap());
+    }
+    assert!(Color::Rgb(255, 0, 0)
+        .shades()
+        .unwrap()
+        .iter()
+        .all(Color::in_srgb_gamut));
+    assert!(palette.parse("tenant-500").unwrap().shades().is_err());
+
+    let mut renderer = test_renderer(TestOptions {
+        palette: palette.clone(),
+        ..Default::default()
+    });
+    renderer.include_styles(
+        StyleBuilder::new()
+            .bg_color(palette.parse("tenant-100").unwrap())
+            .build(),
+    );
+    let css = renderer.render().unwrap().0;
+    assert!(css.starts_with(".bg-tenant-100{background-color:oklch("));
+}
+
+#[test]
+fn test_style_complex_colors() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    renderer.include_styles(
+        StyleBuilder::new()
+            .bg_color(Color::White)
+            .fg_color(ComplexColor::CurrentColor)
+            .border_color(ComplexColor::var("accent"))
+            .outline_color(ComplexColor::mix(
+                Color::Red500,
+                ComplexColor::CurrentColor,
+                25,
+            ))
+            .build(),
+    );
+    assert_eq!(
+        renderer.render().unwrap().0,
+        ".bg-white{background-color:rgb(255,255,255)}\
+         .fg-current{color:currentColor}\
+         .bc-var-accent{border-color:var(--accent)}\
+         .oc-mix-red-500-current-25{outline-color:color-mix(in oklab,rgb(239,68,68) 25%,currentColor)}"
+    );
+}
+
+#[test]
+fn test_style_complex_color_classnames_escaped() {
+    let var = ComplexColor::var("brand x");
+    assert_eq!(var.to_classname(), "var-brand_x");
+    assert_eq!(
+        ComplexColor::mix(var.clone(), Color::White, 50).to_classname(),
+        "mix-var-brand_x-white-50"
+    );
+    // the class name is escaped, but the name can't be written into `var()`
+    assert_eq!(
+        StyleRenderer::<DefaultStyleOptions>::to_css(ColorStyle::Background(var)),
+        Err(StyleError::InvalidName(
+            "brand x".into(),
+            "a variable name may only contain letters, digits, `-` and `_`"
+        ))
+    );
+    assert_eq!(
+        StyleRenderer::<DefaultStyleOptions>::to_css(ColorStyle::Background(ComplexColor::var(
+            "x)}</style>"
+        )))
+        .map_err(|e| e.to_string()),
+        Err("invalid name \"x)}</style>\": a variable name may only contain letters, digits, `-` and `_`".into())
+    );
+    assert_eq!(
+        StyleRenderer::<DefaultStyleOptions>::to_css(ColorStyle::Background(ComplexColor::var(
+            "brand-500"
+        )))
+        .unwrap(),
+        ".bg-var-brand-500{background-color:var(--brand-500)}"
+    );
+}
+
+#[test]
+fn test_stable_hash() {
+    assert_eq!(genkei::stable_hash(""), 0xcbf29ce484222325);
+    assert_eq!(genkei::stable_hash("a"), 0xaf63dc4c8601ec8c);
+    assert_eq!(
+        ComplexColor::Custom("rgb(1 2 3 / 50%)".into()).to_classname(),
+        "custom-638b7ee8129cff28"
+    );
+    assert_eq!(
+        StyleRenderer::<DefaultStyleOptions>::to_css(ColorStyle::Background(ComplexColor::Custom(
+            "color(display-p3 1 0 0)".into()
+        )))
+        .unwrap(),
+        ".bg-custom-2904f95c5b9d4266{background-color:color(display-p3 1 0 0)}"
+    );
+}
+
+/// Returns every built-in style variant with a few sample values.
+fn builtin_styles() -> Vec<Style> {
+    use genkei::*;
+
+    let values = [0, 1, 2, -1, 12];
+    let colors = [
+        AnyColor::from(Color::White),
+        AnyColor::from(Color::Red500),
+        AnyColor::from(Color::rgba(1, 2, 3, 0.5)),
+        AnyColor::from(Color::oklch(0.7, 0.1, 200.0)),
+        AnyColor::from(ComplexColor::CurrentColor),
+        AnyColor::from(ComplexColor::var("x")),
+        AnyColor::from(ComplexColor::Custom("red".into())),
+    ];
+    let breakpoints = [
+        Breakpoint::Sm,
+        Breakpoint::Md,
+        Breakpoint::Lg,
+        Breakpoint::Xl,
+        Breakpoint::Xxl,
+        Breakpoint::Xxxl,
+    ];
+    let container_sizes = [
+        ContainerSize::Xs,
+        ContainerSize::Sm,
+        ContainerSize::Md,
+        ContainerSize::Lg,
+        ContainerSize::Xl,
+        ContainerSize::Xxl,
+        ContainerSize::Xxxl,
+        ContainerSize::Xxxxl,
+        ContainerSize::Xxxxxl,
+        ContainerSize::Xxxxxxl,
+        ContainerSize::Xxxxxxxl,
+    ];
+    let drop_shadows = [
+        DropShadow::Sm,
+        DropShadow::Base,
+        DropShadow::Md,
+        DropShadow::Lg,
+        DropShadow::Xl,
+        DropShadow::Xxl,
+        DropShadow::None,
+    ];
+
+    let mut styles: Vec<Style> = Vec::new();
+    for x in values {
+        styles.extend([
+            Padding::All(x).into(),
+            Padding::Top(x).into(),
+            Padding::Right(x).into(),
+            Padding::Bottom(x).into(),
+            Padding::Left(x).into(),
+            Padding::X(x).into(),
+            Padding::Y(x).into(),
+            Margin::All(x).into(),
+            Margin::Top(x).into(),
+            Margin::Right(x).into(),
+            Margin::Bottom(x).into(),
+            Margin::Left(x).into(),
+            Margin::X(x).into(),
+            Margin::Y(x).into(),
+            Width::Value(x).into(),
+            MinWidth::Value(x).into(),
+            MaxWidth::Value(x).into(),
+            Height::Value(x).into(),
+            MinHeight::Value(x).into(),
+            MaxHeight::Value(x).into(),
+            Font::Weight(x * 100).into(),
+            Flex::Grow(x).into(),
+            Flex::Shrink(x).into(),
+            BorderWidth::All(x).into(),
+            BorderWidth::X(x).into(),
+            BorderWidth::Y(x).into(),
+            BorderWidth::Top(x).into(),
+            BorderWidth::Right(x).into(),
+            BorderWidth::Bottom(x).into(),
+            BorderWidth::Left(x).into(),
+            Border::Radius(x).into(),
+            Outline::Width(x).into(),
+            Grid::TemplateColumns(GridTemplateColumns::Repeat(x)).into(),
+            Grid::TemplateRows(GridTemplateRows::Repeat(x)).into(),
+            Grid::Column(GridColumn::Span(x)).into(),
+            Grid::Column(GridColumn::Start(x)).into(),
+            Grid::Column(GridColumn::End(x)).into(),
+            Grid::Row(GridRow::Span(x)).into(),
+            Grid::Row(GridRow::Start(x)).into(),
+            Grid::Row(GridRow::End(x)).into(),
+            Filter::Blur(x).into(),
+            Filter::Brightness(x).into(),
+            Filter::Contrast(x).into(),
+            Filter::Grayscale(x).into(),
+            Filter::HueRotate(x).into(),
+            Filter::Invert(x).into(),
+            Filter::Saturate(x).into(),
+            Filter::Sepia(x).into(),
+            BackdropFilter::Blur(x).into(),
+            BackdropFilter::Brightness(x).into(),
+            BackdropFilter::Contrast(x).into(),
+            BackdropFilter::Grayscale(x).into(),
+            BackdropFilter::HueRotate(x).into(),
+            BackdropFilter::Invert(x).into(),
+            BackdropFilter::Saturate(x).into(),
+            BackdropFilter::Sepia(x).into(),
+            Gap::Value(x).into(),
+            Gap::Column(x).into(),
+            Gap::Row(x).into(),
+            Opacity::Value(x).into(),
+            Transform::TranslateX(x).into(),
+            Transform::TranslateY(x).into(),
+            Transform::TranslateZ(x).into(),
+            Transform::Rotate(x).into(),
+            Transform::RotateX(x).into(),
+            Transform::RotateY(x).into(),
+            Transform::Scale(x).into(),
+            Transform::ScaleX(x).into(),
+            Transform::ScaleY(x).into(),
+            Transform::SkewX(x).into(),
+            Transform::SkewY(x).into(),
+            Transform::Perspective(x).into(),
+            Transition::Duration(x).into(),
+            Transition::Delay(x).into(),
+        ]);
+    }
+    for (x, y) in [(1, 2), (1, 3), (2, 3), (16, 9)] {
+        styles.extend([
+            Width::Percent(x, y).into(),
+            Height::Percent(x, y).into(),
+            AspectRatio::Ratio(x, y).into(),
+        ]);
+    }
+    for color in colors {
+        styles.extend([
+            Style::from(ColorStyle::Foreground(color.clone())),
+            ColorStyle::Background(color.clone()).into(),
+            Border::Color(color.clone()).into(),
+            Outline::Color(color.clone()).into(),
+            Background::From(color.clone()).into(),
+            Background::Via(color.clone()).into(),
+            Background::To(color).into(),
+        ]);
+    }
+    for color in Color::palette() {
+        styles.extend([
+            Style::from(ColorStyle::Foreground(color)),
+            ColorStyle::Background(color).into(),
+        ]);
+    }
+    for size in container_sizes {
+        styles.push(MaxWidth::Container(size).into());
+    }
+    for breakpoint in breakpoints {
+        styles.push(MaxWidth::Screen(breakpoint).into());
+    }
+    for shadow in drop_shadows {
+        styles.push(Filter::DropShadow(shadow).into());
+        styles.push(BackdropFilter::DropShadow(shadow).into());
+    }
+    styles.extend([
+        Width::Full.into(),
+        Width::Screen.into(),
+        Width::MinContent.into(),
+        Width::MaxContent.into(),
+        Width::FitContent.into(),
+        MinWidth::Full.into(),
+        MinWidth::MinContent.into(),
+        MinWidth::MaxContent.into(),
+        MinWidth::FitContent.into(),
+        MaxWidth::None.into(),
+        MaxWidth::Full.into(),
+        MaxWidth::MinContent.into(),
+        MaxWidth::MaxContent.into(),
+        MaxWidth::FitContent.into(),
+        MaxWidth::Prose.into(),
+        Height::Full.into(),
+        Height::Screen.into(),
+        Height::MinContent.into(),
+        Height::MaxContent.into(),
+        Height::FitContent.into(),
+        MinHeight::Full.into(),
+        MinHeight::MinContent.into(),
+        MinHeight::MaxContent.into(),
+        MinHeight::FitContent.into(),
+        MaxHeight::None.into(),
+        MaxHeight::Full.into(),
+        MaxHeight::Screen.into(),
+        MaxHeight::MinContent.into(),
+        MaxHeight::MaxContent.into(),
+        MaxHeight::FitContent.into(),
+        AspectRatio::Auto.into(),
+        AspectRatio::Square.into(),
+        AspectRatio::Video.into(),
+        Background::None.into(),
+        Background::Image("a.png".into()).into(),
+        Font::Size(FontSize::Xs).into(),
+        Font::Size(FontSize::Sm).into(),
+        Font::Size(FontSize::Base).into(),
+        Font::Size(FontSize::Lg).into(),
+        Font::Size(FontSize::Xl).into(),
+        Font::Size(FontSize::Xxl).into(),
+        Font::Size(FontSize::Xxxl).into(),
+        Font::Size(FontSize::Xxxxl).into(),
+        Font::Family(FontFamily::Sans).into(),
+        Font::Family(FontFamily::Serif).into(),
+        Font::Family(FontFamily::Mono).into(),
+        Font::Style(FontStyle::Normal).into(),
+        Font::Style(FontStyle::Italic).into(),
+        TextAlign::Left.into(),
+        TextAlign::Center.into(),
+        TextAlign::Right.into(),
+        Display::None.into(),
+        Display::Block.into(),
+        Display::Flex.into(),
+        Display::Inline.into(),
+        Display::InlineBlock.into(),
+        Display::Grid.into(),
+        Display::InlineFlex.into(),
+        Flex::Direction(FlexDirection::Row).into(),
+        Flex::Direction(FlexDirection::RowReverse).into(),
+        Flex::Direction(FlexDirection::Column).into(),
+        Flex::Direction(FlexDirection::ColumnReverse).into(),
+        Flex::Wrap(FlexWrap::NoWrap).into(),
+        Flex::Wrap(FlexWrap::Wrap).into(),
+        Flex::Wrap(FlexWrap::WrapReverse).into(),
+        Justify::Content(JustifyContent::Normal).into(),
+        Justify::Content(JustifyContent::FlexStart).into(),
+        Justify::Content(JustifyContent::FlexEnd).into(),
+        Justify::Content(JustifyContent::Center).into(),
+        Justify::Content(JustifyContent::SpaceBetween).into(),
+        Justify::Content(JustifyContent::SpaceAround).into(),
+        Justify::Content(JustifyContent::SpaceEvenly).into(),
+        Justify::Content(JustifyContent::Stretch).into(),
+        Justify::Items(JustifyItems::Start).into(),
+        Justify::Items(JustifyItems::End).into(),
+        Justify::Items(JustifyItems::Center).into(),
+        Justify::Items(JustifyItems::Stretch).into(),
+        Justify::Self_(JustifySelf::Auto).into(),
+        Justify::Self_(JustifySelf::Start).into(),
+        Justify::Self_(JustifySelf::End).into(),
+        Justify::Self_(JustifySelf::Center).into(),
+        Justify::Self_(JustifySelf::Stretch).into(),
+        Align::Content(AlignContent::Normal).into(),
+        Align::Content(AlignContent::Center).into(),
+        Align::Content(AlignContent::FlexStart).into(),
+        Align::Content(AlignContent::FlexEnd).into(),
+        Align::Content(AlignContent::SpaceBetween).into(),
+        Align::Content(AlignContent::SpaceAround).into(),
+        Align::Content(AlignContent::SpaceEvenly).into(),
+        Align::Content(AlignContent::Baseline).into(),
+        Align::Content(AlignContent::Stretch).into(),
+        Align::Items(AlignItems::FlexStart).into(),
+        Align::Items(AlignItems::FlexEnd).into(),
+        Align::Items(AlignItems::Center).into(),
+        Align::Items(AlignItems::Baseline).into(),
+        Align::Items(AlignItems::Stretch).into(),
+        Align::Self_(AlignSelf::Auto).into(),
+        Align::Self_(AlignSelf::FlexStart).into(),
+        Align::Self_(AlignSelf::FlexEnd).into(),
+        Align::Self_(AlignSelf::Center).into(),
+        Align::Self_(AlignSelf::Baseline).into(),
+        Align::Self_(AlignSelf::Stretch).into(),
+        Outline::Style(OutlineStyle::Solid).into(),
+        Outline::Style(OutlineStyle::Dashed).into(),
+        Outline::Style(OutlineStyle::Dotted).into(),
+        Outline::Style(OutlineStyle::Double).into(),
+        Outline::Style(OutlineStyle::Groove).into(),
+        Outline::Style(OutlineStyle::Ridge).into(),
+        Outline::Style(OutlineStyle::Inset).into(),
+        Outline::Style(OutlineStyle::Outset).into(),
+        Outline::Style(OutlineStyle::Hidden).into(),
+        Outline::Style(OutlineStyle::None).into(),
+        Grid::TemplateColumns(GridTemplateColumns::None).into(),
+        Grid::TemplateRows(GridTemplateRows::None).into(),
+        Grid::Column(GridColumn::Auto).into(),
+        Grid::Column(GridColumn::SpanFull).into(),
+        Grid::Column(GridColumn::StartAuto).into(),
+        Grid::Column(GridColumn::EndAuto).into(),
+        Grid::Row(GridRow::Auto).into(),
+        Grid::Row(GridRow::SpanFull).into(),
+        Grid::Row(GridRow::StartAuto).into(),
+        Grid::Row(GridRow::EndAuto).into(),
+        Cursor::Auto.into(),
+        Cursor::Default.into(),
+        Cursor::Pointer.into(),
+        Transform::Origin(TransformOrigin::Center).into(),
+        Transform::Origin(TransformOrigin::Top).into(),
+        Transform::Origin(TransformOrigin::TopRight).into(),
+        Transform::Origin(TransformOrigin::Right).into(),
+        Transform::Origin(TransformOrigin::BottomRight).into(),
+        Transform::Origin(TransformOrigin::Bottom).into(),
+        Transform::Origin(TransformOrigin::BottomLeft).into(),
+        Transform::Origin(TransformOrigin::Left).into(),
+        Transform::Origin(TransformOrigin::TopLeft).into(),
+        Transition::Property(TransitionProperty::None).into(),
+        Transition::Property(TransitionProperty::All).into(),
+        Transition::Property(TransitionProperty::Colors).into(),
+        Transition::Property(TransitionProperty::Opacity).into(),
+        Transition::Property(TransitionProperty::Transform).into(),
+        Transition::Timing(TransitionTiming::Linear).into(),
+        Transition::Timing(TransitionTiming::In).into(),
+        Transition::Timing(TransitionTiming::Out).into(),
+        Transition::Timing(TransitionTiming::InOut).into(),
+        Animation::None.into(),
+        Animation::Spin.into(),
+        Animation::Ping.into(),
+        Animation::Pulse.into(),
+        Animation::Bounce.into(),
+        Content::None.into(),
+        Content::Text("x".into()).into(),
+        Container { name: None }.into(),
+        Container {
+            name: Some("card".into()),
+        }
+        .into(),
+        Marker::new(RelationKind::Group, None).into(),
+        Marker::new(RelationKind::Peer, Some("item".into())).into(),
+    ]);
+    for direction in [
+        GradientDirection::ToTop,
+        GradientDirection::ToTopRight,
+        GradientDirection::ToRight,
+        GradientDirection::ToBottomRight,
+        GradientDirection::ToBottom,
+        GradientDirection::ToBottomLeft,
+        GradientDirection::ToLeft,
+        GradientDirection::ToTopLeft,
+    ] {
+        styles.push(Background::Gradient(direction).into());
+    }
+    for size in [
+        BackgroundSize::Auto,
+        BackgroundSize::Cover,
+        BackgroundSize::Contain,
+    ] {
+        styles.push(Background::Size(size).into());
+    }
+    for position in [
+        BackgroundPosition::Bottom,
+        BackgroundPosition::Center,
+        BackgroundPosition::Left,
+        BackgroundPosition::LeftBottom,
+        BackgroundPosition::LeftTop,
+        BackgroundPosition::Right,
+        BackgroundPosition::RightBottom,
+        BackgroundPosition::RightTop,
+        BackgroundPosition::Top,
+    ] {
+        styles.push(Background::Position(position).into());
+    }
+    for repeat in [
+        BackgroundRepeat::Repeat,
+        BackgroundRepeat::NoRepeat,
+        BackgroundRepeat::RepeatX,
+        BackgroundRepeat::RepeatY,
+        BackgroundRepeat::Round,
+        BackgroundRepeat::Space,
+    ] {
+        styles.push(Background::Repeat(repeat).into());
+    }
+    for clip in [
+        BackgroundClip::Border,
+        BackgroundClip::Padding,
+        BackgroundClip::Content,
+        BackgroundClip::Text,
+    ] {
+        styles.push(Background::Clip(clip).into());
+    }
+
+    // variants wrap a sample of the styles
+    let inner: Style = Padding::All(1).into();
+    for variant in StateVariant::ALL {
+        styles.push(State::new(variant, inner.clone()).into());
+        // group and peer states are pseudo-classes only
+        if variant.is_pseudo_element() {
+            continue;
+        }
+        styles.push(Style::Relation(
+            Relation::new(Marker::new(RelationKind::Group, None), variant),
+            Box::new(inner.clone()),
+        ));
+        styles.push(Style::Relation(
+            Relation::new(Marker::new(RelationKind::Peer, Some("x".into())), variant),
+            Box::new(inner.clone()),
+        ));
+    }
+    let media_queries = breakpoints
+        .iter()
+        .map(|x| MediaQuery::from(*x))
+        .chain(breakpoints.iter().map(|x| MediaQuery::Max(*x)))
+        .chain([
+            MediaQuery::Print,
+            MediaQuery::Portrait,
+            MediaQuery::Landscape,
+            MediaQuery::MotionSafe,
+            MediaQuery::MotionReduce,
+            MediaQuery::ContrastMore,
+            MediaQuery::Raw("(hover: hover)".into()),
+        ]);
+    for mq in media_queries {
+        styles.push(Style::MediaQuery(mq, Box::new(inner.clone())));
+    }
+    for size in container_sizes {
+        styles.push(Style::ContainerQuery(size.into(), Box::new(inner.clone())));
+        styles.push(Style::ContainerQuery(
+            ContainerQuery::Named("card".into(), size),
+            Box::new(inner.clone()),
+        ));
+    }
+    styles.push(Style::DataQuery("open".into(), Box::new(inner.clone())));
+    styles.push(Style::Dark(Box::new(inner)));
+
+    styles
+}
+
+fn assert_builtin_classnames_unique<O: StyleOptions + Clone>(options: O) {
+    let mut classnames = std::collections::BTreeMap::new();
+    for style in builtin_styles() {
+        let mut renderer = StyleRenderer::with_options(options.clone(), false, 4096);
+        let classname = renderer
+            .use_as_classname(std::collections::BTreeSet::from([style.clone()]))
+            .unwrap();
+        if let Some(existing) = classnames.insert(classname.clone(), style.clone()) {
+            assert_eq!(existing, style, "class name {:?} is not unique", classname);
+        }
+    }
+}
+
+#[test]
+fn test_style_builtin_classnames_unique() {
+    assert_builtin_classnames_unique(DefaultStyleOptions);
+    assert_builtin_classnames_unique(TestOptions {
+        class_naming: ClassNaming::Tailwind,
+        ..Default::default()
+    });
+}
+
+#[test]
+fn test_style_classname_collision() {
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
+    let grow = Style::from(genkei::Flex::Grow(1));
+    let one = Palette::new().register("1", Color::White).unwrap();
+    let color = Style::from(ColorStyle::Foreground(one));
+    renderer
+        .use_as_classname(std::collections::BTreeSet::from([grow.clone()]))
+        .unwrap();
+    assert_eq!(
+        renderer.use_as_classname(std::collections::BTreeSet::from([color.clone()])),
+        Err(StyleError::ClassnameCollision(
+            "fg-1".into(),
+            Box::new(grow),
+            Box::new(color)
+        ))
+    );
+}
+
+#[test]
+fn test_style_tailwind_naming() {
+    let mut renderer = test_renderer(TestOptions {
+        class_naming: ClassNaming::Tailwind,
+        ..Default::default()
+    });
+    let classnames = renderer
+        .use_as_classname(
+            StyleBuilder::new()
+                .border(2)
+                .border_top(1)
+                .border_radius(2)
+                .border_color(Color::Slate200)
+                .font_weight(700)
+                .font_family(FontFamily::Mono)
+                .fg_color(Color::rgb(1, 2, 3))
+                .bg_color(ComplexColor::var("surface"))
+                .mt(-2)
+                .flex_grow(1)
+                .mq(Breakpoint::Xxl, |x| x.p(4))
+                .hover(|x| x.fg_color(Color::Sky500))
+                .build(),
+        )
+        .unwrap();
+    let mut classnames: Vec<&str> = classnames.split(' ').collect();
+    classnames.sort();
+    assert_eq!(
+        classnames,
+        [
+            "-mt-2",
+            "2xl:p-4",
+            "bg-[color:var(--surface)]",
+            "border-2",
+            "border-slate-200",
+            "border-t",
+            "font-bold",
+            "font-mono",
+            "grow",
+            "hover:text-sky-500",
+            "rounded-lg",
+            "text-[rgb(1,2,3)]",
+        ]
+    );
+
+    let css = renderer.render().unwrap().0;
+    assert!(css.contains(".border-2{border-width:2px}"));
+    assert!(css.contains("@media(min-width:1536px){.\\32 xl\\:p-4{padding:1rem}}"));
+    assert!(css.contains(".text-\\[rgb\\(1\\,2\\,3\\)\\]{color:rgb(1,2,3)}"));
+}
+
+#[test]
+fn test_style_minified_naming() {
+    let mut renderer = test_renderer(TestOptions {
+        class_naming: ClassNaming::Minified,
+        ..Default::default()
+    });
+    let first = renderer
+        .use_as_classname(StyleBuilder::new().p(4).hover(|x| x.p(2)).build())
+        .unwrap();
+    assert_eq!(first, "a b");
+    // known styles keep their names, new ones get the next names
+    let second = renderer
+        .use_as_classname(StyleBuilder::new().p(4).m(1).group().build())
+        .unwrap();
+    assert_eq!(second, "a c group");
+
+    for x in 0..=100 {
+        renderer.include_style(Margin::Top(x));
+    }
+    let mapping = renderer.minified_classnames().unwrap();
+    assert_eq!(mapping["a"], "p-4");
+    assert_eq!(mapping["b"], "hover:p-2");
+    assert_eq!(mapping["Z"], "mt-48");
+    assert_eq!(mapping["aZ"], "mt-100");
+    assert!(!mapping.contains_key("group"));
+
+    let css = renderer.render().unwrap().0;
+    assert!(css.contains(".a{padding:1rem}"));
+    assert!(css.contains(".b:hover{padding:0.5rem}"));
+    assert!(!css.contains("p-4"));
+}
+
+#[test]
+fn test_style_readable_css() {
+    let mut renderer = test_renderer(TestOptions {
+        readable_css: true,
+        ..Default::default()
+    });
+    renderer.include_styles(
+        StyleBuilder::new()
+            .p(4)
+            .mq(Breakpoint::Md, |x| x.fg_color(Color::rgb(1, 2, 3)))
+            .build(),
+    );
+    let css = renderer.render().unwrap().0;
+    let (comments, rules): (Vec<&str>, Vec<&str>) = css.lines().partition(|x| x.starts_with("/*"));
+    assert_eq!(
+        rules,
+        [
+            ".p-4 {",
+            "  padding: 1rem;",
+            "}",
+            "@media(min-width:768px) {",
+            "  .md\\:fg-rgb-1-2-3 {",
+            "    color: rgb(1,2,3);",
+            "  }",
+            "}",
+        ]
+    );
+    // a section comment and the class name of every rule
+    assert_eq!(
+        comments,
+        ["/* styles */", "/* p-4 */", "/* md:fg-rgb-1-2-3 */"]
+    );
+
+    // the annotations name the styles behind minified class names
+    let mut renderer = test_renderer(TestOptions {
+        readable_css: true,
+        class_naming: ClassNaming::Minified,
+        ..Default::default()
+    });
+    renderer.include_styles(StyleBuilder::new().hover(|x| x.p(4)).build());
+    assert_eq!(
+        renderer.render().unwrap().0,
+        "/* styles */\n/* hover:p-4 */\n.a:hover {\n  padding: 1rem;\n}\n"
+    );
+
+    let reset = StyleRenderer::with_options(
+        TestOptions {
+            readable_css: true,
+            ..Default::default()
+        },
+        true,
+        4096,
+    )
+    .render()
+    .unwrap()
+    .0;
+    assert!(reset.starts_with("/* reset */\n"));
+    // one rule or declaration per line
+    assert!(reset
+        .lines()
+        .all(|x| x.ends_with(" {") || x.ends_with(';') || x.ends_with('}') || x.ends_with("*/")));
+}
+
+#[test]
+fn test_style_external_stylesheet() {
+    let mut result = Renderer::render_tag(genkei::div().p(4)).unwrap();
+    let etag = result.etag();
+    let stylesheet = result.stylesheet("app");
+    assert_eq!(result.html(), "<div class=p-4></div>");
+    assert_eq!(
+        etag,
+        format!("\"{:016x}\"", genkei::stable_hash("<div class=p-4></div>"))
+    );
+
+    let hash = genkei::stable_hash(stylesheet.css());
+    assert_eq!(stylesheet.hash(), hash);
+    assert_eq!(stylesheet.filename(), format!("app.{:016x}.css", hash));
+    assert!(stylesheet.css().ends_with(".p-4{padding:1rem}"));
+    assert_eq!(
+        stylesheet.link("/assets/").to_html().unwrap(),
+        format!(
+            "<link href=/assets/{} rel=stylesheet />",
+            stylesheet.filename()
+        )
+    );
+
+    // the same css gets the same file name
+    let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(true);
+    renderer.include_style(genkei::Padding::All(4));
+    let (same, _) = renderer.render_stylesheet("app").unwrap();
+    assert_eq!(same.filename(), stylesheet.filename());
+}
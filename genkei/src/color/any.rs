@@ -0,0 +1,53 @@
+use crate::{Color, ColorValue, ComplexColor, StyleError};
+
+/// Either a simple or a complex color.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AnyColor {
+    Simple(Color),
+    Complex(ComplexColor),
+}
+
+impl From<Color> for AnyColor {
+    fn from(value: Color) -> Self {
+        Self::Simple(value)
+    }
+}
+
+impl From<ComplexColor> for AnyColor {
+    fn from(value: ComplexColor) -> Self {
+        Self::Complex(value)
+    }
+}
+
+impl AnyColor {
+    /// Returns the color with zero opacity, used to fade gradients out. Complex and palette
+    /// colors keep their hue through `color-mix(in oklab, color 0%, transparent)`.
+    pub(crate) fn transparent(&self) -> Self {
+        match self {
+            Self::Simple(x @ Color::Named(_)) => {
+                Self::Complex(ComplexColor::mix(*x, Color::Transparent, 0))
+            }
+            Self::Simple(x) => Self::Simple(x.alpha(0.0).unwrap_or(Color::Transparent)),
+            Self::Complex(x) => Self::Complex(ComplexColor::mix(x.clone(), Color::Transparent, 0)),
+        }
+    }
+}
+
+impl ColorValue for AnyColor {
+    fn write_color_name(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            Self::Simple(x) => x.write_color_name(stream),
+            Self::Complex(x) => x.write_color_name(stream),
+        }
+    }
+
+    fn write_color_value<T>(&self, stream: &mut String, options: &T) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            Self::Simple(x) => x.write_css_value(stream, options),
+            Self::Complex(x) => x.write_css_value(stream, options),
+        }
+    }
+}
@@ -1,39 +1,88 @@
-use std::{
-    collections::hash_map::DefaultHasher,
-    hash::{Hash, Hasher},
-};
-
-use crate::{ColorValue, Str, StyleError, StyleOptions};
+use crate::{AnyColor, ColorValue, Str, StyleError, StyleOptions};
+use std::fmt::Write;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ComplexColor {
+    /// A raw css color value, the class name is `custom-` followed by the [`stable_hash`] of
+    /// the value in 16 hex digits.
+    ///
+    /// [`stable_hash`]: crate::stable_hash
     Custom(String),
+    /// A reference to a css custom property, e.g. a design token, written as `var(--name)`.
+    /// Names with other characters than letters, digits, `-` and `_` fail to render.
+    Var(String),
+    /// The `currentColor` keyword, the value of the `color` property.
+    CurrentColor,
+    /// `color-mix(in oklab, first X%, second)`, mixes X percent of the first color into the
+    /// second.
+    Mix(Box<AnyColor>, Box<AnyColor>, u8),
 }
 
 impl ComplexColor {
+    /// Creates a reference to the css custom property `--name`.
+    pub fn var(name: impl Into<String>) -> Self {
+        Self::Var(name.into())
+    }
+
+    /// Creates a `color-mix()` of `percent` of `first` and the rest of `second`.
+    pub fn mix(first: impl Into<AnyColor>, second: impl Into<AnyColor>, percent: u8) -> Self {
+        Self::Mix(
+            Box::new(first.into()),
+            Box::new(second.into()),
+            percent.min(100),
+        )
+    }
+
     pub fn to_classname(&self) -> Str {
-        match self {
-            Self::Custom(s) => {
-                let mut hasher = DefaultHasher::new();
-                s.hash(&mut hasher);
-                let hash = hasher.finish();
-                Str::from(format!("custom-{}", hash))
-            }
-        }
+        let mut name = String::with_capacity(32);
+        // writing to a string cannot fail
+        self.write_color_name(&mut name).unwrap();
+        Str::from(name)
     }
 
     pub(crate) fn write_color_name(&self, stream: &mut String) -> Result<(), StyleError> {
-        stream.push_str(&self.to_classname());
+        match self {
+            Self::Custom(s) => write!(stream, "custom-{:016x}", crate::stable_hash(s))?,
+            Self::Var(name) => {
+                stream.push_str("var-");
+                crate::style::write_arbitrary_classname(stream, name)?;
+            }
+            Self::CurrentColor => stream.push_str("current"),
+            Self::Mix(first, second, percent) => {
+                stream.push_str("mix-");
+                first.write_color_name(stream)?;
+                stream.push('-');
+                second.write_color_name(stream)?;
+                write!(stream, "-{}", percent)?;
+            }
+        };
         Ok(())
     }
 
     pub(crate) fn write_css_value<T: StyleOptions>(
         &self,
         stream: &mut String,
-        _options: &T,
+        options: &T,
     ) -> Result<(), StyleError> {
         match self {
             Self::Custom(s) => stream.push_str(s),
+            Self::Var(name) => {
+                if !crate::style::is_css_name(name) {
+                    return Err(StyleError::InvalidName(
+                        name.clone(),
+                        "a variable name may only contain letters, digits, `-` and `_`",
+                    ));
+                }
+                write!(stream, "var(--{})", name)?;
+            }
+            Self::CurrentColor => stream.push_str("currentColor"),
+            Self::Mix(first, second, percent) => {
+                stream.push_str("color-mix(in oklab,");
+                first.write_color_value(stream, options)?;
+                write!(stream, " {}%,", percent)?;
+                second.write_color_value(stream, options)?;
+                stream.push(')');
+            }
         };
         Ok(())
     }
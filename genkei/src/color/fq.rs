@@ -49,7 +49,18 @@ impl std::fmt::Display for FloatQuantized {
     }
 }
 
+impl From<FloatQuantized> for f64 {
+    fn from(value: FloatQuantized) -> Self {
+        value.as_f64()
+    }
+}
+
 impl FloatQuantized {
+    /// Restricts the value to the given range.
+    pub fn clamp(self, min: f64, max: f64) -> Self {
+        FloatQuantized(self.0.clamp(min, max))
+    }
+
     pub fn as_i32(&self) -> i32 {
         (self.0 * 10000.0).round() as i32
     }
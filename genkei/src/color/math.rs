@@ -0,0 +1,192 @@
+use crate::{Color, FloatQuantized, StyleError};
+
+impl Color {
+    /// Returns the red, green, blue and alpha channels in the range `0.0..=1.0`. Palette
+    /// colors fail, resolve them with [`Palette::resolve`] first.
+    ///
+    /// [`Palette::resolve`]: crate::Palette::resolve
+    pub fn to_srgb(&self) -> Result<(f64, f64, f64, f64), StyleError> {
+        match self.to_rgb() {
+            Color::Rgb(r, g, b) => Ok((r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0, 1.0)),
+            Color::Rgba(r, g, b, a) => Ok((
+                r as f64 / 255.0,
+                g as f64 / 255.0,
+                b as f64 / 255.0,
+                a.as_f64(),
+            )),
+            x => Err(x.unresolved()),
+        }
+    }
+
+    /// Creates a color from channels in the range `0.0..=1.0`, `Rgb` if it's opaque.
+    pub fn from_srgb(r: f64, g: f64, b: f64, alpha: f64) -> Self {
+        let channel = |x: f64| (x.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let alpha = alpha.clamp(0.0, 1.0);
+        if FloatQuantized::from(alpha) == FloatQuantized::from(1.0) {
+            Color::Rgb(channel(r), channel(g), channel(b))
+        } else {
+            Color::Rgba(channel(r), channel(g), channel(b), alpha.into())
+        }
+    }
+
+    /// Returns the hue in degrees, the saturation and lightness in percent and the alpha.
+    pub fn to_hsla(&self) -> Result<(f64, f64, f64, f64), StyleError> {
+        if let Color::Hsl(h, s, l) = self {
+            return Ok((h.as_f64(), s.as_f64(), l.as_f64(), 1.0));
+        }
+
+        let (r, g, b, a) = self.to_srgb()?;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let d = max - min;
+        if d == 0.0 {
+            return Ok((0.0, 0.0, l * 100.0, a));
+        }
+
+        let s = d / (1.0 - (2.0 * l - 1.0).abs());
+        Ok((hue(r, g, b, max, d), s * 100.0, l * 100.0, a))
+    }
+
+    /// Creates a color from the hue in degrees, the saturation and lightness in percent and the
+    /// alpha. Opaque colors are kept as `Hsl`, `Hsl` has no alpha channel.
+    pub fn from_hsla(h: f64, s: f64, l: f64, alpha: f64) -> Self {
+        let h = h.rem_euclid(360.0);
+        let s = s.clamp(0.0, 100.0);
+        let l = l.clamp(0.0, 100.0);
+        if FloatQuantized::from(alpha.clamp(0.0, 1.0)) == FloatQuantized::from(1.0) {
+            Color::Hsl(h.into(), s.into(), l.into())
+        } else {
+            let (r, g, b) = hsl_to_srgb(h, s / 100.0, l / 100.0);
+            Self::from_srgb(r, g, b, alpha)
+        }
+    }
+
+    /// Converts the color to `Hsl`, the alpha is dropped.
+    pub fn to_hsl(self) -> Result<Self, StyleError> {
+        let (h, s, l, _) = self.to_hsla()?;
+        Ok(Self::from_hsla(h, s, l, 1.0))
+    }
+
+    /// Returns the hue in degrees, the whiteness and blackness in percent and the alpha.
+    pub fn to_hwba(&self) -> Result<(f64, f64, f64, f64), StyleError> {
+        let (h, _, _, a) = self.to_hsla()?;
+        let (r, g, b, _) = self.to_srgb()?;
+        let w = r.min(g).min(b);
+        let v = r.max(g).max(b);
+        Ok((h, w * 100.0, (1.0 - v) * 100.0, a))
+    }
+
+    /// Creates a color from the hue in degrees, the whiteness and blackness in percent and the
+    /// alpha.
+    pub fn from_hwba(h: f64, w: f64, b: f64, alpha: f64) -> Self {
+        let mut w = w.clamp(0.0, 100.0) / 100.0;
+        let mut bl = b.clamp(0.0, 100.0) / 100.0;
+        if w + bl >= 1.0 {
+            let gray = w / (w + bl);
+            return Self::from_srgb(gray, gray, gray, alpha);
+        }
+        if w + bl == 0.0 {
+            w = 0.0;
+            bl = 0.0;
+        }
+
+        let (r, g, b) = hsl_to_srgb(h.rem_euclid(360.0), 1.0, 0.5);
+        let channel = |x: f64| x * (1.0 - w - bl) + w;
+        Self::from_srgb(channel(r), channel(g), channel(b), alpha)
+    }
+
+    /// Increases the lightness by `amount` percentage points.
+    pub fn lighten(self, amount: f64) -> Result<Self, StyleError> {
+        let (h, s, l, a) = self.to_hsla()?;
+        Ok(Self::from_hsla(h, s, l + amount, a))
+    }
+
+    /// Decreases the lightness by `amount` percentage points.
+    pub fn darken(self, amount: f64) -> Result<Self, StyleError> {
+        self.lighten(-amount)
+    }
+
+    /// Increases the saturation by `amount` percentage points, negative values desaturate.
+    pub fn saturate(self, amount: f64) -> Result<Self, StyleError> {
+        let (h, s, l, a) = self.to_hsla()?;
+        Ok(Self::from_hsla(h, s + amount, l, a))
+    }
+
+    /// Mixes the colors in sRGB, a `weight` of `0.0` returns `self` and `1.0` returns `other`.
+    pub fn mix(self, other: impl Into<Color>, weight: f64) -> Result<Self, StyleError> {
+        let weight = weight.clamp(0.0, 1.0);
+        let (r1, g1, b1, a1) = self.to_srgb()?;
+        let (r2, g2, b2, a2) = other.into().to_srgb()?;
+        let lerp = |x: f64, y: f64| x + (y - x) * weight;
+        Ok(Self::from_srgb(
+            lerp(r1, r2),
+            lerp(g1, g2),
+            lerp(b1, b2),
+            lerp(a1, a2),
+        ))
+    }
+
+    /// Returns the WCAG relative luminance in the range `0.0..=1.0`, the alpha is ignored.
+    pub fn relative_luminance(&self) -> Result<f64, StyleError> {
+        let linear = |x: f64| {
+            if x <= 0.04045 {
+                x / 12.92
+            } else {
+                ((x + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let (r, g, b, _) = self.to_srgb()?;
+        Ok(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+    }
+
+    /// Returns the WCAG contrast ratio in the range `1.0..=21.0`.
+    pub fn contrast_ratio(&self, other: &Color) -> Result<f64, StyleError> {
+        let a = self.relative_luminance()?;
+        let b = other.relative_luminance()?;
+        Ok((a.max(b) + 0.05) / (a.min(b) + 0.05))
+    }
+
+    /// Returns whether the color as text on `background` meets WCAG AA, a ratio of at least
+    /// 4.5, or 3.0 for large text.
+    pub fn meets_aa(&self, background: &Color, large_text: bool) -> Result<bool, StyleError> {
+        Ok(self.contrast_ratio(background)? >= if large_text { 3.0 } else { 4.5 })
+    }
+
+    /// Returns whether the color as text on `background` meets WCAG AAA, a ratio of at least
+    /// 7.0, or 4.5 for large text.
+    pub fn meets_aaa(&self, background: &Color, large_text: bool) -> Result<bool, StyleError> {
+        Ok(self.contrast_ratio(background)? >= if large_text { 4.5 } else { 7.0 })
+    }
+
+    /// Returns white or black, whichever has the higher contrast on `background`.
+    pub fn readable_on(background: &Color) -> Result<Color, StyleError> {
+        if Color::White.contrast_ratio(background)? >= Color::Black.contrast_ratio(background)? {
+            Ok(Color::White)
+        } else {
+            Ok(Color::Black)
+        }
+    }
+}
+
+fn hue(r: f64, g: f64, b: f64, max: f64, d: f64) -> f64 {
+    let h = if max == r {
+        (g - b) / d
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    (h * 60.0).rem_euclid(360.0)
+}
+
+/// Converts the hue in degrees and the saturation and lightness in `0.0..=1.0` to sRGB.
+pub(crate) fn hsl_to_srgb(h: f64, s: f64, l: f64) -> (f64, f64, f64) {
+    let channel = |n: f64| {
+        let k = (n + h / 30.0) % 12.0;
+        let a = s * l.min(1.0 - l);
+        l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+
+    (channel(0.0), channel(8.0), channel(4.0))
+}
@@ -1,11 +1,19 @@
 use crate::StyleError;
 
+mod any;
 mod complex;
 mod fq;
+mod math;
+mod oklab;
+mod palette;
+mod parse;
+mod scale;
 mod simple;
 
+pub use any::*;
 pub use complex::*;
 pub use fq::*;
+pub use palette::*;
 pub use simple::*;
 
 pub trait ColorValue {
@@ -0,0 +1,174 @@
+use crate::{Color, FloatQuantized, StyleError};
+
+impl Color {
+    pub fn oklab(
+        l: impl Into<FloatQuantized>,
+        a: impl Into<FloatQuantized>,
+        b: impl Into<FloatQuantized>,
+    ) -> Self {
+        Self::Oklab(l.into(), a.into(), b.into())
+    }
+
+    pub fn oklch(
+        l: impl Into<FloatQuantized>,
+        c: impl Into<FloatQuantized>,
+        h: impl Into<FloatQuantized>,
+    ) -> Self {
+        Self::Oklch(l.into(), c.into(), h.into())
+    }
+
+    /// Returns the OKLab lightness in the range `0.0..=1.0`, the `a` and `b` axes and the alpha.
+    pub fn to_oklab(&self) -> Result<(f64, f64, f64, f64), StyleError> {
+        if let Some((l, a, b)) = self.oklab_components() {
+            return Ok((l, a, b, 1.0));
+        }
+
+        let (r, g, b, alpha) = self.to_srgb()?;
+        let (l, a, b) = linear_srgb_to_oklab(to_linear(r), to_linear(g), to_linear(b));
+        Ok((l, a, b, alpha))
+    }
+
+    /// Creates a color from the OKLab components and the alpha. Opaque colors are kept as
+    /// `Oklab`, `Oklab` has no alpha channel.
+    pub fn from_oklab(l: f64, a: f64, b: f64, alpha: f64) -> Self {
+        let l = l.clamp(0.0, 1.0);
+        if FloatQuantized::from(alpha.clamp(0.0, 1.0)) == FloatQuantized::from(1.0) {
+            Color::Oklab(l.into(), a.into(), b.into())
+        } else {
+            let (r, g, b) = oklab_to_linear_srgb(l, a, b);
+            Self::from_srgb(from_linear(r), from_linear(g), from_linear(b), alpha)
+        }
+    }
+
+    /// Returns the OKLCH lightness in the range `0.0..=1.0`, the chroma, the hue in degrees and
+    /// the alpha.
+    pub fn to_oklch(&self) -> Result<(f64, f64, f64, f64), StyleError> {
+        if let Color::Oklch(l, c, h) = *self {
+            return Ok((l.as_f64(), c.as_f64(), h.as_f64(), 1.0));
+        }
+
+        let (l, a, b, alpha) = self.to_oklab()?;
+        let c = (a * a + b * b).sqrt();
+        // the hue of an achromatic color is powerless, keep it stable
+        let h = if c < 1e-4 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+        Ok((l, c, h, alpha))
+    }
+
+    /// Creates a color from the OKLCH components and the alpha. Opaque colors are kept as
+    /// `Oklch`, `Oklch` has no alpha channel.
+    pub fn from_oklch(l: f64, c: f64, h: f64, alpha: f64) -> Self {
+        let l = l.clamp(0.0, 1.0);
+        let c = c.max(0.0);
+        let h = h.rem_euclid(360.0);
+        if FloatQuantized::from(alpha.clamp(0.0, 1.0)) == FloatQuantized::from(1.0) {
+            Color::Oklch(l.into(), c.into(), h.into())
+        } else {
+            let (a, b) = lch_to_lab(c, h);
+            Self::from_oklab(l, a, b, alpha)
+        }
+    }
+
+    /// Returns whether the color can be displayed in sRGB without clipping, only `Oklab` and
+    /// `Oklch` colors can be outside.
+    pub fn in_srgb_gamut(&self) -> bool {
+        const EPSILON: f64 = 1e-4;
+        match self.oklab_components() {
+            Some((l, a, b)) => {
+                let (r, g, b) = oklab_to_linear_srgb(l, a, b);
+                [r, g, b]
+                    .iter()
+                    .all(|x| (-EPSILON..=1.0 + EPSILON).contains(x))
+            }
+            None => true,
+        }
+    }
+
+    /// Returns the red, green, blue and alpha channels in the display-p3 color space, clipped to
+    /// the range `0.0..=1.0`.
+    pub fn to_display_p3(&self) -> Result<(f64, f64, f64, f64), StyleError> {
+        let (_, _, _, alpha) = self.to_srgb()?;
+        let (r, g, b) = match self.oklab_components() {
+            Some((l, a, b)) => oklab_to_linear_srgb(l, a, b),
+            None => {
+                let (r, g, b, _) = self.to_srgb()?;
+                (to_linear(r), to_linear(g), to_linear(b))
+            }
+        };
+        let channel = |x: f64| from_linear(x.clamp(0.0, 1.0));
+        Ok((
+            channel(0.822_462_1 * r + 0.177_538 * g),
+            channel(0.033_194_1 * r + 0.966_805_8 * g),
+            channel(0.017_082_7 * r + 0.072_397_4 * g + 0.910_519_9 * b),
+            alpha,
+        ))
+    }
+
+    /// Returns the OKLab lightness and axes of `Oklab` and `Oklch` colors.
+    fn oklab_components(&self) -> Option<(f64, f64, f64)> {
+        match *self {
+            Color::Oklab(l, a, b) => Some((l.as_f64(), a.as_f64(), b.as_f64())),
+            Color::Oklch(l, c, h) => {
+                let (a, b) = lch_to_lab(c.as_f64(), h.as_f64());
+                Some((l.as_f64(), a, b))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Converts an `Oklab` or `Oklch` color to sRGB, channels outside of the gamut are clipped.
+pub(crate) fn oklab_to_srgb(color: &Color) -> (f64, f64, f64) {
+    let (l, a, b) = color.oklab_components().unwrap_or_default();
+    let (r, g, b) = oklab_to_linear_srgb(l, a, b);
+    let channel = |x: f64| from_linear(x.clamp(0.0, 1.0));
+    (channel(r), channel(g), channel(b))
+}
+
+fn lch_to_lab(c: f64, h: f64) -> (f64, f64) {
+    let h = h.to_radians();
+    (c * h.cos(), c * h.sin())
+}
+
+fn to_linear(x: f64) -> f64 {
+    if x <= 0.04045 {
+        x / 12.92
+    } else {
+        ((x + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn from_linear(x: f64) -> f64 {
+    if x <= 0.003_130_8 {
+        x * 12.92
+    } else {
+        1.055 * x.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn linear_srgb_to_oklab(r: f64, g: f64, b: f64) -> (f64, f64, f64) {
+    let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+    let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+    let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+
+    (
+        0.210_454_255_3 * l + 0.793_617_785_0 * m - 0.004_072_046_8 * s,
+        1.977_998_495_1 * l - 2.428_592_205_0 * m + 0.450_593_709_9 * s,
+        0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766_0 * s,
+    )
+}
+
+fn oklab_to_linear_srgb(l: f64, a: f64, b: f64) -> (f64, f64, f64) {
+    let l_ = (l + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+    let m_ = (l - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+    let s_ = (l - 0.089_484_177_5 * a - 1.291_485_548_0 * b).powi(3);
+
+    (
+        4.076_741_662_1 * l_ - 3.307_711_591_3 * m_ + 0.230_969_929_2 * s_,
+        -1.268_438_004_6 * l_ + 2.609_757_401_1 * m_ - 0.341_319_396_5 * s_,
+        -0.004_196_086_3 * l_ - 0.703_418_614_7 * m_ + 1.707_614_701_0 * s_,
+    )
+}
@@ -0,0 +1,171 @@
+use crate::{Color, Str, StyleError};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard};
+
+/// The interned palette color names, a handle is the index into `names`.
+struct Names {
+    names: Vec<Arc<str>>,
+    handles: BTreeMap<Arc<str>, u32>,
+}
+
+static NAMES: RwLock<Names> = RwLock::new(Names {
+    names: Vec::new(),
+    handles: BTreeMap::new(),
+});
+
+fn names() -> RwLockReadGuard<'static, Names> {
+    NAMES.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// The name of a user-defined palette color, e.g. `brand-500`. Names are interned once per
+/// process, so the handle is `Copy` while the name can be built at runtime. Only the names
+/// are shared, the values stay in the [`Palette`] of each renderer.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PaletteName(u32);
+
+impl PaletteName {
+    fn intern(name: &str) -> Self {
+        if let Some(name) = Self::lookup(name) {
+            return name;
+        }
+
+        let mut names = NAMES.write().unwrap_or_else(PoisonError::into_inner);
+        // another thread may have interned the name in between
+        if let Some(&handle) = names.handles.get(name) {
+            return Self(handle);
+        }
+        let handle = names.names.len() as u32;
+        let name: Arc<str> = Arc::from(name);
+        names.names.push(name.clone());
+        names.handles.insert(name, handle);
+        Self(handle)
+    }
+
+    /// Returns the handle of an interned name, parsing doesn't intern unknown names.
+    pub(crate) fn lookup(name: &str) -> Option<Self> {
+        names().handles.get(name).map(|&handle| Self(handle))
+    }
+
+    /// Returns the name, e.g. `brand-500`.
+    pub fn name(&self) -> Str {
+        Str::from(names().names[self.0 as usize].to_string())
+    }
+}
+
+impl std::fmt::Debug for PaletteName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.name())
+    }
+}
+
+// ordered by name, the handles depend on which name was interned first
+impl Ord for PaletteName {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        let names = names();
+        names.names[self.0 as usize].cmp(&names.names[other.0 as usize])
+    }
+}
+
+impl PartialOrd for PaletteName {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// User-defined palette colors, e.g. `brand-500`, provided by the
+/// [`StyleOptions::palette`](crate::StyleOptions::palette) of a renderer.
+///
+/// Registering a color returns a [`Color::Named`] handle. The handle has its own class name
+/// and is resolved by the palette when the css is rendered, so renderers with different
+/// palettes can use the same names.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Palette {
+    colors: BTreeMap<PaletteName, Color>,
+}
+
+/// The palette of options without user-defined colors.
+pub(crate) static EMPTY_PALETTE: Palette = Palette::new();
+
+impl Palette {
+    pub const fn new() -> Self {
+        Self {
+            colors: BTreeMap::new(),
+        }
+    }
+
+    /// Registers a named color, e.g. `palette.register("brand-500", Color::Rgb(..))`. The name
+    /// is used as class name and resolved by [`Color::from_str`], so it should not collide with
+    /// the built-in palette. Registering a name again replaces its value.
+    ///
+    /// A named `value` is resolved first, it fails if it isn't registered in this palette.
+    ///
+    /// [`Color::from_str`]: std::str::FromStr::from_str
+    pub fn register(&mut self, name: &str, value: Color) -> Result<Color, StyleError> {
+        if !crate::style::is_css_name(name) {
+            return Err(StyleError::InvalidName(
+                name.to_string(),
+                "a palette color name may only contain letters, digits, `-` and `_`",
+            ));
+        }
+        let value = self.resolve_value(&value)?;
+        let name = PaletteName::intern(name);
+        self.colors.insert(name, value);
+        Ok(Color::Named(name))
+    }
+
+    /// Registers multiple named colors, see [`Palette::register`].
+    pub fn register_all<N: AsRef<str>>(
+        &mut self,
+        colors: impl IntoIterator<Item = (N, Color)>,
+    ) -> Result<(), StyleError> {
+        for (name, value) in colors {
+            self.register(name.as_ref(), value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Registers the shades of `base` as `{name}-50` to `{name}-950`, see [`Color::shades`].
+    pub fn register_shades(&mut self, name: &str, base: Color) -> Result<[Color; 11], StyleError> {
+        let shades = self.resolve_value(&base)?.shades()?;
+        let mut colors = shades;
+        for ((color, shade), value) in colors.iter_mut().zip(Color::SHADES).zip(shades) {
+            *color = self.register(&format!("{}-{}", name, shade), value)?;
+        }
+
+        Ok(colors)
+    }
+
+    /// Returns the value of a registered color, or `None` if it isn't registered. Other colors
+    /// are returned as they are.
+    pub fn resolve(&self, color: &Color) -> Option<Color> {
+        match *color {
+            Color::Named(name) => self.colors.get(&name).copied(),
+            color => Some(color),
+        }
+    }
+
+    /// Parses a color registered in this palette by its name, e.g. `brand-500`, or any other
+    /// color, see [`Color::from_str`].
+    ///
+    /// [`Color::from_str`]: std::str::FromStr::from_str
+    pub fn parse(&self, s: &str) -> Result<Color, StyleError> {
+        let color = s.parse()?;
+        self.resolve(&color)
+            .map(|_| color)
+            .ok_or_else(|| StyleError::InvalidSimpleColor(s.trim().to_string()))
+    }
+
+    /// Returns the registered colors, sorted by name.
+    pub fn colors(&self) -> impl Iterator<Item = Color> + '_ {
+        self.colors.keys().map(|&name| Color::Named(name))
+    }
+
+    fn resolve_value(&self, value: &Color) -> Result<Color, StyleError> {
+        self.resolve(value).ok_or_else(|| value.unresolved())
+    }
+}
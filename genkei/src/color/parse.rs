@@ -0,0 +1,312 @@
+use crate::{Color, FloatQuantized, StyleError};
+
+/// The css named colors, see <https://www.w3.org/TR/css-color-4/#named-colors>.
+const NAMED_COLORS: [(&str, u8, u8, u8); 148] = [
+    ("aliceblue", 240, 248, 255),
+    ("antiquewhite", 250, 235, 215),
+    ("aqua", 0, 255, 255),
+    ("aquamarine", 127, 255, 212),
+    ("azure", 240, 255, 255),
+    ("beige", 245, 245, 220),
+    ("bisque", 255, 228, 196),
+    ("black", 0, 0, 0),
+    ("blanchedalmond", 255, 235, 205),
+    ("blue", 0, 0, 255),
+    ("blueviolet", 138, 43, 226),
+    ("brown", 165, 42, 42),
+    ("burlywood", 222, 184, 135),
+    ("cadetblue", 95, 158, 160),
+    ("chartreuse", 127, 255, 0),
+    ("chocolate", 210, 105, 30),
+    ("coral", 255, 127, 80),
+    ("cornflowerblue", 100, 149, 237),
+    ("cornsilk", 255, 248, 220),
+    ("crimson", 220, 20, 60),
+    ("cyan", 0, 255, 255),
+    ("darkblue", 0, 0, 139),
+    ("darkcyan", 0, 139, 139),
+    ("darkgoldenrod", 184, 134, 11),
+    ("darkgray", 169, 169, 169),
+    ("darkgreen", 0, 100, 0),
+    ("darkgrey", 169, 169, 169),
+    ("darkkhaki", 189, 183, 107),
+    ("darkmagenta", 139, 0, 139),
+    ("darkolivegreen", 85, 107, 47),
+    ("darkorange", 255, 140, 0),
+    ("darkorchid", 153, 50, 204),
+    ("darkred", 139, 0, 0),
+    ("darksalmon", 233, 150, 122),
+    ("darkseagreen", 143, 188, 143),
+    ("darkslateblue", 72, 61, 139),
+    ("darkslategray", 47, 79, 79),
+    ("darkslategrey", 47, 79, 79),
+    ("darkturquoise", 0, 206, 209),
+    ("darkviolet", 148, 0, 211),
+    ("deeppink", 255, 20, 147),
+    ("deepskyblue", 0, 191, 255),
+    ("dimgray", 105, 105, 105),
+    ("dimgrey", 105, 105, 105),
+    ("dodgerblue", 30, 144, 255),
+    ("firebrick", 178, 34, 34),
+    ("floralwhite", 255, 250, 240),
+    ("forestgreen", 34, 139, 34),
+    ("fuchsia", 255, 0, 255),
+    ("gainsboro", 220, 220, 220),
+    ("ghostwhite", 248, 248, 255),
+    ("gold", 255, 215, 0),
+    ("goldenrod", 218, 165, 32),
+    ("gray", 128, 128, 128),
+    ("green", 0, 128, 0),
+    ("greenyellow", 173, 255, 47),
+    ("grey", 128, 128, 128),
+    ("honeydew", 240, 255, 240),
+    ("hotpink", 255, 105, 180),
+    ("indianred", 205, 92, 92),
+    ("indigo", 75, 0, 130),
+    ("ivory", 255, 255, 240),
+    ("khaki", 240, 230, 140),
+    ("lavender", 230, 230, 250),
+    ("lavenderblush", 255, 240, 245),
+    ("lawngreen", 124, 252, 0),
+    ("lemonchiffon", 255, 250, 205),
+    ("lightblue", 173, 216, 230),
+    ("lightcoral", 240, 128, 128),
+    ("lightcyan", 224, 255, 255),
+    ("lightgoldenrodyellow", 250, 250, 210),
+    ("lightgray", 211, 211, 211),
+    ("lightgreen", 144, 238, 144),
+    ("lightgrey", 211, 211, 211),
+    ("lightpink", 255, 182, 193),
+    ("lightsalmon", 255, 160, 122),
+    ("lightseagreen", 32, 178, 170),
+    ("lightskyblue", 135, 206, 250),
+    ("lightslategray", 119, 136, 153),
+    ("lightslategrey", 119, 136, 153),
+    ("lightsteelblue", 176, 196, 222),
+    ("lightyellow", 255, 255, 224),
+    ("lime", 0, 255, 0),
+    ("limegreen", 50, 205, 50),
+    ("linen", 250, 240, 230),
+    ("magenta", 255, 0, 255),
+    ("maroon", 128, 0, 0),
+    ("mediumaquamarine", 102, 205, 170),
+    ("mediumblue", 0, 0, 205),
+    ("mediumorchid", 186, 85, 211),
+    ("mediumpurple", 147, 112, 219),
+    ("mediumseagreen", 60, 179, 113),
+    ("mediumslateblue", 123, 104, 238),
+    ("mediumspringgreen", 0, 250, 154),
+    ("mediumturquoise", 72, 209, 204),
+    ("mediumvioletred", 199, 21, 133),
+    ("midnightblue", 25, 25, 112),
+    ("mintcream", 245, 255, 250),
+    ("mistyrose", 255, 228, 225),
+    ("moccasin", 255, 228, 181),
+    ("navajowhite", 255, 222, 173),
+    ("navy", 0, 0, 128),
+    ("oldlace", 253, 245, 230),
+    ("olive", 128, 128, 0),
+    ("olivedrab", 107, 142, 35),
+    ("orange", 255, 165, 0),
+    ("orangered", 255, 69, 0),
+    ("orchid", 218, 112, 214),
+    ("palegoldenrod", 238, 232, 170),
+    ("palegreen", 152, 251, 152),
+    ("paleturquoise", 175, 238, 238),
+    ("palevioletred", 219, 112, 147),
+    ("papayawhip", 255, 239, 213),
+    ("peachpuff", 255, 218, 185),
+    ("peru", 205, 133, 63),
+    ("pink", 255, 192, 203),
+    ("plum", 221, 160, 221),
+    ("powderblue", 176, 224, 230),
+    ("purple", 128, 0, 128),
+    ("rebeccapurple", 102, 51, 153),
+    ("red", 255, 0, 0),
+    ("rosybrown", 188, 143, 143),
+    ("royalblue", 65, 105, 225),
+    ("saddlebrown", 139, 69, 19),
+    ("salmon", 250, 128, 114),
+    ("sandybrown", 244, 164, 96),
+    ("seagreen", 46, 139, 87),
+    ("seashell", 255, 245, 238),
+    ("sienna", 160, 82, 45),
+    ("silver", 192, 192, 192),
+    ("skyblue", 135, 206, 235),
+    ("slateblue", 106, 90, 205),
+    ("slategray", 112, 128, 144),
+    ("slategrey", 112, 128, 144),
+    ("snow", 255, 250, 250),
+    ("springgreen", 0, 255, 127),
+    ("steelblue", 70, 130, 180),
+    ("tan", 210, 180, 140),
+    ("teal", 0, 128, 128),
+    ("thistle", 216, 191, 216),
+    ("tomato", 255, 99, 71),
+    ("turquoise", 64, 224, 208),
+    ("violet", 238, 130, 238),
+    ("wheat", 245, 222, 179),
+    ("white", 255, 255, 255),
+    ("whitesmoke", 245, 245, 245),
+    ("yellow", 255, 255, 0),
+    ("yellowgreen", 154, 205, 50),
+];
+
+/// Parses the css color syntaxes: hex, `rgb()`, `rgba()`, `hsl()`, `hsla()`, `oklab()`,
+/// `oklch()` and named colors.
+pub(crate) fn parse_css_color(input: &str) -> Result<Color, StyleError> {
+    let s = input.to_ascii_lowercase();
+    let error = |reason| StyleError::InvalidColor(input.into(), reason);
+
+    if let Some(hex) = s.strip_prefix('#') {
+        return parse_hex(hex).map_err(error);
+    }
+
+    if let Some((name, args)) = s.split_once('(') {
+        let args = args
+            .strip_suffix(')')
+            .ok_or_else(|| error("missing closing parenthesis"))?;
+        return match name.trim_end() {
+            "rgb" | "rgba" => parse_rgb(args).map_err(error),
+            "hsl" | "hsla" => parse_hsl(args).map_err(error),
+            "oklab" => parse_oklab(args).map_err(error),
+            "oklch" => parse_oklch(args).map_err(error),
+            _ => Err(error("unsupported color function")),
+        };
+    }
+
+    NAMED_COLORS
+        .binary_search_by(|(name, ..)| (*name).cmp(s.as_str()))
+        .map(|i| {
+            let (_, r, g, b) = NAMED_COLORS[i];
+            Color::Rgb(r, g, b)
+        })
+        .map_err(|_| StyleError::InvalidSimpleColor(input.into()))
+}
+
+fn parse_hex(hex: &str) -> Result<Color, &'static str> {
+    if !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return Err("invalid hex digit");
+    }
+
+    let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).map(|x| x * 17);
+    let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16);
+    let (r, g, b, a) = match hex.len() {
+        3 => (digit(0), digit(1), digit(2), None),
+        4 => (digit(0), digit(1), digit(2), Some(digit(3))),
+        6 => (byte(0), byte(2), byte(4), None),
+        8 => (byte(0), byte(2), byte(4), Some(byte(6))),
+        _ => return Err("expected 3, 4, 6 or 8 hex digits"),
+    };
+    let (r, g, b) = (r.unwrap(), g.unwrap(), b.unwrap());
+
+    Ok(match a {
+        Some(a) => Color::Rgba(r, g, b, FloatQuantized::from(a.unwrap() as f64 / 255.0)),
+        None => Color::Rgb(r, g, b),
+    })
+}
+
+/// Splits the arguments of both the legacy `rgb(1, 2, 3, 0.5)` and the modern
+/// `rgb(1 2 3 / 50%)` syntax into the three components and the optional alpha.
+fn split_args(args: &str) -> Result<([&str; 3], Option<&str>), &'static str> {
+    let parts: Vec<&str> = if args.contains(',') {
+        args.split(',').map(str::trim).collect()
+    } else {
+        let (components, alpha) = match args.split_once('/') {
+            Some((components, alpha)) => (components, Some(alpha.trim())),
+            None => (args, None),
+        };
+        let mut parts: Vec<&str> = components.split_whitespace().collect();
+        if parts.len() == 3 {
+            parts.extend(alpha);
+        } else {
+            return Err("expected 3 components");
+        }
+        parts
+    };
+
+    match parts.as_slice() {
+        [a, b, c] => Ok(([a, b, c], None)),
+        [a, b, c, alpha] => Ok(([a, b, c], Some(alpha))),
+        _ => Err("expected 3 components and an optional alpha"),
+    }
+}
+
+fn parse_number(value: &str) -> Result<f64, &'static str> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|x| x.is_finite())
+        .ok_or("invalid number")
+}
+
+/// Parses a number or percentage, `100%` equals `scale`.
+fn parse_scaled(value: &str, scale: f64) -> Result<f64, &'static str> {
+    match value.strip_suffix('%') {
+        Some(x) => Ok(parse_number(x)? / 100.0 * scale),
+        None => parse_number(value),
+    }
+}
+
+fn parse_alpha(value: Option<&str>) -> Result<f64, &'static str> {
+    match value {
+        Some(x) => Ok(parse_scaled(x, 1.0)?.clamp(0.0, 1.0)),
+        None => Ok(1.0),
+    }
+}
+
+fn parse_rgb(args: &str) -> Result<Color, &'static str> {
+    let ([r, g, b], alpha) = split_args(args)?;
+    let channel = |x: &str| parse_scaled(x, 255.0).map(|x| x.clamp(0.0, 255.0).round() as u8);
+    let (r, g, b) = (channel(r)?, channel(g)?, channel(b)?);
+
+    Ok(match alpha {
+        Some(_) => Color::Rgba(r, g, b, FloatQuantized::from(parse_alpha(alpha)?)),
+        None => Color::Rgb(r, g, b),
+    })
+}
+
+fn parse_hue(value: &str) -> Result<f64, &'static str> {
+    let degrees = if let Some(x) = value.strip_suffix("deg") {
+        parse_number(x)?
+    } else if let Some(x) = value.strip_suffix("grad") {
+        parse_number(x)? * 0.9
+    } else if let Some(x) = value.strip_suffix("rad") {
+        parse_number(x)?.to_degrees()
+    } else if let Some(x) = value.strip_suffix("turn") {
+        parse_number(x)? * 360.0
+    } else {
+        parse_number(value)?
+    };
+
+    Ok(degrees.rem_euclid(360.0))
+}
+
+fn parse_hsl(args: &str) -> Result<Color, &'static str> {
+    let ([h, s, l], alpha) = split_args(args)?;
+    let h = parse_hue(h)?;
+    let s = parse_scaled(s, 100.0)?.clamp(0.0, 100.0);
+    let l = parse_scaled(l, 100.0)?.clamp(0.0, 100.0);
+
+    Ok(Color::from_hsla(h, s, l, parse_alpha(alpha)?))
+}
+
+fn parse_oklab(args: &str) -> Result<Color, &'static str> {
+    let ([l, a, b], alpha) = split_args(args)?;
+    let l = parse_scaled(l, 1.0)?;
+    // `100%` of the `a` and `b` axes is 0.4
+    let a = parse_scaled(a, 0.4)?;
+    let b = parse_scaled(b, 0.4)?;
+
+    Ok(Color::from_oklab(l, a, b, parse_alpha(alpha)?))
+}
+
+fn parse_oklch(args: &str) -> Result<Color, &'static str> {
+    let ([l, c, h], alpha) = split_args(args)?;
+    let l = parse_scaled(l, 1.0)?;
+    // `100%` chroma is 0.4
+    let c = parse_scaled(c, 0.4)?;
+    let h = parse_hue(h)?;
+
+    Ok(Color::from_oklch(l, c, h, parse_alpha(alpha)?))
+}
@@ -0,0 +1,65 @@
+use crate::{Color, StyleError};
+
+/// The OKLCH lightness and the chroma relative to the 500 shade of every step, following the
+/// built-in palette.
+const STEPS: [(u16, f64, f64); 11] = [
+    (50, 0.971, 0.055),
+    (100, 0.936, 0.135),
+    (200, 0.885, 0.262),
+    (300, 0.808, 0.481),
+    (400, 0.704, 0.806),
+    (500, 0.637, 1.0),
+    (600, 0.577, 1.034),
+    (700, 0.505, 0.899),
+    (800, 0.444, 0.747),
+    (900, 0.396, 0.595),
+    (950, 0.258, 0.388),
+];
+
+impl Color {
+    /// The steps of a shade scale, `50` to `950`.
+    pub const SHADES: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+    /// Generates the 11 shades `50` to `950` with `self` as the 500 shade. The shades keep the
+    /// hue in OKLCH and are spaced like the built-in palette, the chroma is reduced where a
+    /// shade would be outside of the sRGB gamut. Palette colors fail, resolve them with
+    /// [`Palette::resolve`](crate::Palette::resolve) first.
+    pub fn shades(self) -> Result<[Color; 11], StyleError> {
+        let (_, base, _) = STEPS[5];
+        let (l, c, h, _) = self.to_oklch()?;
+        let (lightest, darkest) = (STEPS[0].1, STEPS[10].1);
+
+        Ok(STEPS.map(|(shade, target, chroma)| {
+            if shade == 500 {
+                return self;
+            }
+
+            // stretch the lightness between the base and the lightest or darkest shade
+            let l = if target > base {
+                l + (target - base) / (lightest - base) * (lightest - l)
+            } else {
+                l - (base - target) / (base - darkest) * (l - darkest)
+            };
+            gamut_map(l, c * chroma, h)
+        }))
+    }
+}
+
+/// Reduces the chroma until the color fits into sRGB.
+fn gamut_map(l: f64, c: f64, h: f64) -> Color {
+    let color = Color::from_oklch(l, c, h, 1.0);
+    if color.in_srgb_gamut() {
+        return color;
+    }
+
+    let (mut low, mut high) = (0.0, c);
+    for _ in 0..16 {
+        let mid = (low + high) / 2.0;
+        if Color::from_oklch(l, mid, h, 1.0).in_srgb_gamut() {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Color::from_oklch(l, low, h, 1.0)
+}
@@ -1,4 +1,4 @@
-use crate::{ColorValue, FloatQuantized, Str, StyleError, StyleOptions};
+use crate::{ColorSyntax, ColorValue, FloatQuantized, PaletteName, Str, StyleError, StyleOptions};
 use std::str::FromStr;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -496,6 +496,14 @@ pub enum Color {
     Rgb(u8, u8, u8),
     Rgba(u8, u8, u8, FloatQuantized),
     Hsl(FloatQuantized, FloatQuantized, FloatQuantized),
+    /// OKLab lightness in the range `0.0..=1.0` and the `a` and `b` axes.
+    Oklab(FloatQuantized, FloatQuantized, FloatQuantized),
+    /// OKLCH lightness in the range `0.0..=1.0`, chroma and hue in degrees.
+    Oklch(FloatQuantized, FloatQuantized, FloatQuantized),
+    /// A user-defined palette color, e.g. `brand-500`, see
+    /// [`Palette::register`](crate::Palette::register). It only has a value in a palette,
+    /// resolve it with [`Palette::resolve`](crate::Palette::resolve) for the color math.
+    Named(PaletteName),
 }
 
 impl Color {
@@ -855,6 +863,47 @@ impl Color {
         ]
         .into_iter()
     }
+
+    /// Returns the built-in named colors, except `transparent`, e.g. for defining design
+    /// tokens. User-defined colors are in the [`Palette`](crate::Palette) of the options.
+    pub fn palette() -> impl Iterator<Item = Color> {
+        [Color::White, Color::Black]
+            .into_iter()
+            .chain(Self::rose())
+            .chain(Self::pink())
+            .chain(Self::fuchsia())
+            .chain(Self::purple())
+            .chain(Self::violet())
+            .chain(Self::indigo())
+            .chain(Self::blue())
+            .chain(Self::sky())
+            .chain(Self::cyan())
+            .chain(Self::teal())
+            .chain(Self::emerald())
+            .chain(Self::green())
+            .chain(Self::lime())
+            .chain(Self::yellow())
+            .chain(Self::amber())
+            .chain(Self::orange())
+            .chain(Self::red())
+            .chain(Self::stone())
+            .chain(Self::zinc())
+            .chain(Self::gray())
+            .chain(Self::slate())
+    }
+
+    /// Returns whether this is a named palette color, see [`Color::palette`].
+    pub fn is_palette(&self) -> bool {
+        !matches!(
+            self,
+            Self::Transparent
+                | Self::Rgb(..)
+                | Self::Rgba(..)
+                | Self::Hsl(..)
+                | Self::Oklab(..)
+                | Self::Oklch(..)
+        )
+    }
 }
 
 impl Color {
@@ -1093,20 +1142,35 @@ impl Color {
             Self::Slate950 => Self::Rgb(2, 6, 23),
             Self::White => Self::Rgb(255, 255, 255),
             Self::Black => Self::Rgb(0, 0, 0),
-            Self::Transparent => Self::Rgb(0, 0, 0),
+            Self::Transparent => Self::Rgba(0, 0, 0, FloatQuantized::from(0.0)),
+            Self::Hsl(h, s, l) => {
+                let (r, g, b) =
+                    super::math::hsl_to_srgb(h.as_f64(), s.as_f64() / 100.0, l.as_f64() / 100.0);
+                Self::from_srgb(r, g, b, 1.0)
+            }
+            Self::Oklab(..) | Self::Oklch(..) => {
+                let (r, g, b) = super::oklab::oklab_to_srgb(&self);
+                Self::from_srgb(r, g, b, 1.0)
+            }
             _ => self,
         }
     }
 
-    pub fn alpha(self, alpha: f64) -> Self {
+    /// Returns the color with the given alpha, it fails for unresolved palette colors.
+    pub fn alpha(self, alpha: f64) -> Result<Self, StyleError> {
         let alpha = FloatQuantized::from(alpha);
         match self.to_rgb() {
-            Color::Rgb(r, g, b) => Color::Rgba(r, g, b, alpha),
-            Color::Rgba(r, g, b, _) => Color::Rgba(r, g, b, alpha),
-            x => x,
+            Color::Rgb(r, g, b) => Ok(Color::Rgba(r, g, b, alpha)),
+            Color::Rgba(r, g, b, _) => Ok(Color::Rgba(r, g, b, alpha)),
+            x => Err(x.unresolved()),
         }
     }
 
+    /// The error of color math on a palette color, it has no value outside of its palette.
+    pub(crate) fn unresolved(&self) -> StyleError {
+        StyleError::InvalidSimpleColor(self.to_classname().to_string())
+    }
+
     pub fn to_classname(&self) -> Str {
         match self {
             Self::Rose050 => "rose-50".into(),
@@ -1348,17 +1412,71 @@ impl Color {
             Self::Rgb(r, g, b) => Str::from(format!("rgb-{}-{}-{}", r, g, b)),
             Self::Rgba(r, g, b, a) => Str::from(format!("rgba-{}-{}-{}-{}", r, g, b, a.as_i32())),
             Self::Hsl(h, s, l) => Str::from(format!("hsl-{}-{}-{}", h, s, l)),
+            Self::Oklab(l, a, b) => Str::from(format!("oklab-{}-{}-{}", l, a, b)),
+            Self::Oklch(l, c, h) => Str::from(format!("oklch-{}-{}-{}", l, c, h)),
+            Self::Named(name) => name.name(),
         }
     }
 
-    pub fn to_css_value(&self) -> Str {
-        let color = self.to_rgb();
-        match color {
-            Color::Rgb(r, g, b) => Str::from(format!("rgb({},{},{})", r, g, b)),
-            Color::Rgba(r, g, b, a) => Str::from(format!("rgba({},{},{},{})", r, g, b, a)),
-            Color::Hsl(h, s, l) => Str::from(format!("hsl({},{}%,{}%)", h, s, l)),
-            _ => unreachable!(),
+    pub fn to_css_value(&self) -> Result<Str, StyleError> {
+        self.to_css_value_with(ColorSyntax::Auto)
+    }
+
+    /// Returns the css value in the given syntax, it fails for unresolved palette colors.
+    pub fn to_css_value_with(&self, syntax: ColorSyntax) -> Result<Str, StyleError> {
+        if let Color::Named(..) = self {
+            return Err(self.unresolved());
         }
+
+        Ok(match syntax {
+            ColorSyntax::Auto => {
+                // keep the precision of `hsl()` and `oklch()` instead of rounding to rgb
+                let color = match self {
+                    Color::Hsl(..) | Color::Oklab(..) | Color::Oklch(..) => *self,
+                    _ => self.to_rgb(),
+                };
+                match color {
+                    Color::Hsl(h, s, l) => Str::from(format!("hsl({},{}%,{}%)", h, s, l)),
+                    Color::Oklab(l, a, b) => Str::from(format!("oklab({} {} {})", l, a, b)),
+                    Color::Oklch(l, c, h) => Str::from(format!("oklch({} {} {})", l, c, h)),
+                    _ => color.to_css_value_with(ColorSyntax::Rgb)?,
+                }
+            }
+            ColorSyntax::Rgb => match self.to_rgb() {
+                Color::Rgb(r, g, b) => Str::from(format!("rgb({},{},{})", r, g, b)),
+                Color::Rgba(r, g, b, a) => Str::from(format!("rgba({},{},{},{})", r, g, b, a)),
+                _ => unreachable!(),
+            },
+            ColorSyntax::Hex => match self.to_rgb() {
+                Color::Rgb(r, g, b) => Str::from(format!("#{:02x}{:02x}{:02x}", r, g, b)),
+                Color::Rgba(r, g, b, a) => Str::from(format!(
+                    "#{:02x}{:02x}{:02x}{:02x}",
+                    r,
+                    g,
+                    b,
+                    (a.as_f64() * 255.0).round() as u8
+                )),
+                _ => unreachable!(),
+            },
+            ColorSyntax::Oklch => {
+                let (l, c, h, a) = self.to_oklch()?;
+                let (l, c, h) = (
+                    FloatQuantized::from(l),
+                    FloatQuantized::from(c),
+                    FloatQuantized::from(h),
+                );
+                with_alpha(format!("oklch({} {} {}", l, c, h), a)
+            }
+            ColorSyntax::DisplayP3 => {
+                let (r, g, b, a) = self.to_display_p3()?;
+                let (r, g, b) = (
+                    FloatQuantized::from(r),
+                    FloatQuantized::from(g),
+                    FloatQuantized::from(b),
+                );
+                with_alpha(format!("color(display-p3 {} {} {}", r, g, b), a)
+            }
+        })
     }
 
     pub(crate) fn write_color_name(&self, stream: &mut String) -> Result<(), StyleError> {
@@ -1369,9 +1487,20 @@ impl Color {
     pub(crate) fn write_css_value<T: StyleOptions>(
         &self,
         stream: &mut String,
-        _options: &T,
+        options: &T,
     ) -> Result<(), StyleError> {
-        stream.push_str(&self.to_css_value());
+        // unregistered names fail even as tokens, their variable wouldn't be defined
+        let color = options
+            .palette()
+            .resolve(self)
+            .ok_or_else(|| self.unresolved())?;
+        if options.design_tokens() && self.is_palette() {
+            stream.push_str("var(--color-");
+            self.write_color_name(stream)?;
+            stream.push(')');
+        } else {
+            options.color(stream, &color)?;
+        }
         Ok(())
     }
 
@@ -1384,6 +1513,16 @@ impl Color {
     }
 }
 
+/// Closes a space separated color function, appending the alpha unless it's opaque.
+fn with_alpha(mut value: String, alpha: f64) -> Str {
+    let alpha = FloatQuantized::from(alpha);
+    if alpha != FloatQuantized::from(1.0) {
+        value.push_str(&format!(" / {}", alpha));
+    }
+    value.push(')');
+    Str::from(value)
+}
+
 impl FromStr for Color {
     type Err = StyleError;
 
@@ -1626,7 +1765,10 @@ impl FromStr for Color {
             "slate-900" => Ok(Self::Slate900),
             "slate-950" => Ok(Self::Slate950),
 
-            _ => Err(StyleError::InvalidSimpleColor(s.into())),
+            _ => match PaletteName::lookup(s) {
+                Some(name) => Ok(Self::Named(name)),
+                None => super::parse::parse_css_color(s),
+            },
         }
     }
 }
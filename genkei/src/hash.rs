@@ -0,0 +1,16 @@
+/// Hashes free-form input for class names, e.g. [`ComplexColor::Custom`], and content for
+/// stylesheet file names and ETags.
+///
+/// This is the 64-bit FNV-1a hash of the UTF-8 bytes. Unlike the std hashers it is part of the
+/// public contract and doesn't change between builds or Rust releases, so class names and file
+/// names stay the same across deploys.
+///
+/// [`ComplexColor::Custom`]: crate::ComplexColor::Custom
+pub fn stable_hash(value: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    value.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    })
+}
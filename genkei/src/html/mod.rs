@@ -8,14 +8,13 @@ mod tag;
 pub mod deprecated;
 #[cfg(feature = "htmx")]
 pub mod htmx;
+// the macros are `#[macro_export]`ed at the crate root, there's nothing to re-export
 #[cfg(feature = "macros")]
 pub mod macros;
 
 pub use attributes::HtmlAttribute;
 #[cfg(feature = "deprecated")]
 pub use deprecated::*;
-#[cfg(feature = "macros")]
-pub use macros::*;
 pub use renderer::RenderError;
 pub use renderer::RenderResult;
 pub use renderer::Renderer;
@@ -165,6 +164,3 @@ tag_def!(summary);
 // Web Components
 tag_def!(slot);
 tag_def!(template);
-
-#[cfg(feature = "deprecated")]
-pub use deprecated::*;
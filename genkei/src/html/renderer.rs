@@ -1,4 +1,5 @@
 use super::Tag;
+use crate::{DefaultStyleOptions, StyleOptions};
 use std::{collections::BTreeSet, error::Error};
 
 #[derive(Debug, Clone)]
@@ -20,6 +21,19 @@ impl RenderResult {
         std::mem::take(&mut self.css)
     }
 
+    /// Takes the css as an external stylesheet named `{name}.{hash}.css`, to be referenced by
+    /// [`Stylesheet::link`](crate::Stylesheet::link) instead of inlining the css.
+    #[cfg(feature = "style")]
+    pub fn stylesheet(&mut self, name: &str) -> crate::Stylesheet {
+        crate::Stylesheet::new(name, self.css())
+    }
+
+    /// Returns a strong ETag of the html, e.g. `"a1b2c3d4e5f60718"` including the quotes. Call
+    /// it before taking the html with [`RenderResult::html`].
+    pub fn etag(&self) -> String {
+        format!("\"{:016x}\"", crate::stable_hash(&self.html))
+    }
+
     #[cfg(feature = "style")]
     pub fn styles(&mut self) -> BTreeSet<crate::Style> {
         std::mem::take(&mut self.styles)
@@ -58,28 +72,41 @@ impl From<crate::StyleError> for RenderError {
     }
 }
 
+/// Renders tags to html and their styles to css with the style options `Opt`, e.g.
+/// minified class names or readable css, see [`Renderer::with_options`].
 #[derive(Debug, Clone)]
-pub struct Renderer {
+pub struct Renderer<Opt: StyleOptions = DefaultStyleOptions> {
     buffer: String,
     #[cfg(feature = "style")]
-    style_renderer: crate::StyleRenderer<crate::DefaultStyleOptions>,
+    style_renderer: crate::StyleRenderer<Opt>,
     #[cfg(feature = "style")]
     style_or_classname: bool,
     tags: Vec<Tag>,
 }
 
-impl Default for Renderer {
+impl<Opt: StyleOptions> Default for Renderer<Opt> {
     fn default() -> Self {
-        Self::new()
+        Self::with_options(Opt::default())
     }
 }
 
 impl Renderer {
     pub fn new() -> Self {
+        Self::with_options(DefaultStyleOptions)
+    }
+
+    pub fn render_tag(tag: Tag) -> Result<RenderResult, RenderError> {
+        Self::render_tag_with_options(tag, DefaultStyleOptions)
+    }
+}
+
+impl<Opt: StyleOptions> Renderer<Opt> {
+    /// Creates a renderer whose html class names and css follow `options`.
+    pub fn with_options(options: Opt) -> Self {
         Self {
             buffer: String::with_capacity(1024),
             #[cfg(feature = "style")]
-            style_renderer: crate::StyleRenderer::new(true),
+            style_renderer: crate::StyleRenderer::with_options(options, true, 4096),
             #[cfg(feature = "style")]
             style_or_classname: false,
             tags: Vec::new(),
@@ -175,6 +202,7 @@ impl Renderer {
             tag.render(&mut self)?;
         }
 
+        #[cfg(feature = "style")]
         let (css, styles) = self.style_renderer.render()?;
 
         Ok(RenderResult {
@@ -186,8 +214,9 @@ impl Renderer {
         })
     }
 
-    pub fn render_tag(tag: Tag) -> Result<RenderResult, RenderError> {
-        let mut renderer = Self::new();
+    /// Renders a single tag, see [`Renderer::with_options`].
+    pub fn render_tag_with_options(tag: Tag, options: Opt) -> Result<RenderResult, RenderError> {
+        let mut renderer = Self::with_options(options);
         tag.render(&mut renderer)?;
         Ok(RenderResult {
             html: renderer.buffer,
@@ -200,7 +229,7 @@ impl Renderer {
 }
 
 impl Tag {
-    fn render(self, renderer: &mut Renderer) -> Result<(), RenderError> {
+    fn render<Opt: StyleOptions>(self, renderer: &mut Renderer<Opt>) -> Result<(), RenderError> {
         let Self {
             tag_name,
             mut attributes,
@@ -1,6 +1,7 @@
 mod attribute;
 mod children;
 mod color;
+mod hash;
 #[cfg(feature = "html")]
 mod html;
 #[cfg(feature = "style")]
@@ -10,6 +11,7 @@ mod text_content;
 pub use attribute::*;
 pub use children::*;
 pub use color::*;
+pub use hash::*;
 #[cfg(feature = "html")]
 pub use html::*;
 #[cfg(feature = "style")]
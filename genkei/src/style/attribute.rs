@@ -1,4 +1,7 @@
-use crate::{MediaQuery, State, Str, Style, StyleBuilder};
+use crate::{
+    Breakpoint, ContainerQuery, Marker, MediaQuery, Relation, RelationKind, State, StateVariant,
+    Str, Style, StyleBuilder,
+};
 
 /// A trait for adding styles.
 pub trait Styleable: Sized {
@@ -94,6 +97,240 @@ pub trait Styleable: Sized {
         )
     }
 
+    /// Add styles for the given state.
+    fn state(
+        self,
+        variant: StateVariant,
+        style: impl FnOnce(StyleBuilder) -> StyleBuilder,
+    ) -> Self::Output {
+        self.styles(
+            style(StyleBuilder::new())
+                .build()
+                .into_iter()
+                .map(|style| State::new(variant, style)),
+        )
+    }
+
+    /// Add styles for when the element is the first child.
+    #[inline]
+    fn first(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::First, style)
+    }
+
+    /// Add styles for when the element is the last child.
+    #[inline]
+    fn last(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::Last, style)
+    }
+
+    /// Add styles for when the element is an odd child.
+    #[inline]
+    fn odd(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::Odd, style)
+    }
+
+    /// Add styles for when the element is an even child.
+    #[inline]
+    fn even(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::Even, style)
+    }
+
+    /// Add styles for when the element has no children.
+    #[inline]
+    fn empty(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::Empty, style)
+    }
+
+    /// Add styles for when the element is checked. Prefixed with `on_` as `checked` is the html
+    /// attribute.
+    #[inline]
+    fn on_checked(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::Checked, style)
+    }
+
+    /// Add styles for when the element is required.
+    #[inline]
+    fn required(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::Required, style)
+    }
+
+    /// Add styles for when the element is invalid.
+    #[inline]
+    fn invalid(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::Invalid, style)
+    }
+
+    /// Add styles for when the element is read-only.
+    #[inline]
+    fn read_only(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::ReadOnly, style)
+    }
+
+    /// Add styles for when the element is disabled. Prefixed with `on_` as `disabled` is the html
+    /// attribute.
+    #[inline]
+    fn on_disabled(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::Disabled, style)
+    }
+
+    /// Add styles for when the element or one of its descendants is focused.
+    #[inline]
+    fn focus_within(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::FocusWithin, style)
+    }
+
+    /// Add styles for the placeholder text of an input. Prefixed with `on_` as `placeholder` is
+    /// the html attribute.
+    #[inline]
+    fn on_placeholder(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::Placeholder, style)
+    }
+
+    /// Add styles for the `::before` pseudo-element, see [`Content`](crate::Content).
+    #[inline]
+    fn before(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::Before, style)
+    }
+
+    /// Add styles for the `::after` pseudo-element, see [`Content`](crate::Content).
+    #[inline]
+    fn after(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.state(StateVariant::After, style)
+    }
+
+    /// Mark the element as a group, see [`Styleable::group_state`].
+    #[inline]
+    fn group(self) -> Self::Output {
+        self.style(Marker::new(RelationKind::Group, None))
+    }
+
+    /// Mark the element as a named group, e.g. `group/item`, for nested groups.
+    #[inline]
+    fn group_named(self, name: impl Into<Str>) -> Self::Output {
+        self.style(Marker::new(RelationKind::Group, Some(name.into())))
+    }
+
+    /// Mark the element as a peer, see [`Styleable::peer_state`].
+    #[inline]
+    fn peer(self) -> Self::Output {
+        self.style(Marker::new(RelationKind::Peer, None))
+    }
+
+    /// Mark the element as a named peer, e.g. `peer/email`.
+    #[inline]
+    fn peer_named(self, name: impl Into<Str>) -> Self::Output {
+        self.style(Marker::new(RelationKind::Peer, Some(name.into())))
+    }
+
+    /// Add styles for when the given ancestor or preceding sibling is in the given state. The
+    /// state must be a pseudo-class and a marker name may only contain letters, digits, `-` and
+    /// `_`, otherwise rendering fails.
+    fn relation(
+        self,
+        marker: Marker,
+        variant: StateVariant,
+        style: impl FnOnce(StyleBuilder) -> StyleBuilder,
+    ) -> Self::Output {
+        let relation = Relation::new(marker, variant);
+        self.styles(
+            style(StyleBuilder::new())
+                .build()
+                .into_iter()
+                .map(Box::new)
+                .map(|style| Style::Relation(relation.clone(), style)),
+        )
+    }
+
+    /// Add styles for when the closest ancestor marked with [`Styleable::group`] is in the
+    /// given state.
+    #[inline]
+    fn group_state(
+        self,
+        variant: StateVariant,
+        style: impl FnOnce(StyleBuilder) -> StyleBuilder,
+    ) -> Self::Output {
+        self.relation(Marker::new(RelationKind::Group, None), variant, style)
+    }
+
+    /// Add styles for when the ancestor marked with [`Styleable::group_named`] is in the given
+    /// state.
+    #[inline]
+    fn group_named_state(
+        self,
+        name: impl Into<Str>,
+        variant: StateVariant,
+        style: impl FnOnce(StyleBuilder) -> StyleBuilder,
+    ) -> Self::Output {
+        self.relation(
+            Marker::new(RelationKind::Group, Some(name.into())),
+            variant,
+            style,
+        )
+    }
+
+    /// Add styles for when a preceding sibling marked with [`Styleable::peer`] is in the given
+    /// state.
+    #[inline]
+    fn peer_state(
+        self,
+        variant: StateVariant,
+        style: impl FnOnce(StyleBuilder) -> StyleBuilder,
+    ) -> Self::Output {
+        self.relation(Marker::new(RelationKind::Peer, None), variant, style)
+    }
+
+    /// Add styles for when the preceding sibling marked with [`Styleable::peer_named`] is in
+    /// the given state.
+    #[inline]
+    fn peer_named_state(
+        self,
+        name: impl Into<Str>,
+        variant: StateVariant,
+        style: impl FnOnce(StyleBuilder) -> StyleBuilder,
+    ) -> Self::Output {
+        self.relation(
+            Marker::new(RelationKind::Peer, Some(name.into())),
+            variant,
+            style,
+        )
+    }
+
+    /// Add styles for when the group is hovered.
+    #[inline]
+    fn group_hover(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.group_state(StateVariant::Hover, style)
+    }
+
+    /// Add styles for when the group is focused.
+    #[inline]
+    fn group_focus(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.group_state(StateVariant::Focus, style)
+    }
+
+    /// Add styles for when the peer is hovered.
+    #[inline]
+    fn peer_hover(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.peer_state(StateVariant::Hover, style)
+    }
+
+    /// Add styles for when the peer is focused.
+    #[inline]
+    fn peer_focus(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.peer_state(StateVariant::Focus, style)
+    }
+
+    /// Add styles for when the peer is checked.
+    #[inline]
+    fn peer_checked(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.peer_state(StateVariant::Checked, style)
+    }
+
+    /// Add styles for when the peer is invalid.
+    #[inline]
+    fn peer_invalid(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.peer_state(StateVariant::Invalid, style)
+    }
+
     /// Add styles for a specific media query.
     fn mq(
         self,
@@ -110,6 +347,33 @@ pub trait Styleable: Sized {
         )
     }
 
+    /// Add styles for screen sizes from `min` up to, but excluding, `max`, e.g. `md:max-lg:`.
+    fn mq_range(
+        self,
+        min: Breakpoint,
+        max: Breakpoint,
+        style: impl FnOnce(StyleBuilder) -> StyleBuilder,
+    ) -> Self::Output {
+        self.mq(min, |builder| builder.mq(MediaQuery::Max(max), style))
+    }
+
+    /// Add styles for a specific container query, see
+    /// [`ContainerTrait::container`](crate::ContainerTrait::container).
+    fn cq(
+        self,
+        container_query: impl Into<ContainerQuery>,
+        style: impl FnOnce(StyleBuilder) -> StyleBuilder,
+    ) -> Self::Output {
+        let cq = container_query.into();
+        self.styles(
+            style(StyleBuilder::new())
+                .build()
+                .into_iter()
+                .map(Box::new)
+                .map(|style| Style::ContainerQuery(cq.clone(), style)),
+        )
+    }
+
     /// Add styles for a specific data query.
     fn dq(
         self,
@@ -125,4 +389,15 @@ pub trait Styleable: Sized {
                 .map(|style| Style::DataQuery(dq.clone(), style)),
         )
     }
+
+    /// Add styles for dark mode, see [`DarkMode`](crate::DarkMode).
+    fn dark(self, style: impl FnOnce(StyleBuilder) -> StyleBuilder) -> Self::Output {
+        self.styles(
+            style(StyleBuilder::new())
+                .build()
+                .into_iter()
+                .map(Box::new)
+                .map(Style::Dark),
+        )
+    }
 }
@@ -0,0 +1,40 @@
+use crate::{Keyframes, StyleError};
+
+/// A rule that a style depends on, which is emitted once per stylesheet regardless of how many
+/// styles use it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GlobalRule {
+    /// @keyframes name { frames }
+    Keyframes(Keyframes),
+}
+
+impl GlobalRule {
+    /// Fails if two `@keyframes` share a name but differ in their frames, only one of them
+    /// would take effect.
+    pub(crate) fn check_conflicts<'a>(
+        rules: impl IntoIterator<Item = &'a GlobalRule>,
+    ) -> Result<(), StyleError> {
+        let mut previous: Option<&Keyframes> = None;
+        for rule in rules {
+            let GlobalRule::Keyframes(keyframes) = rule;
+            // the rules are ordered, keyframes with the same name are adjacent
+            if let Some(previous) = previous.filter(|x| x.name == keyframes.name) {
+                if previous.frames != keyframes.frames {
+                    return Err(StyleError::InvalidKeyframes(
+                        keyframes.name.to_string(),
+                        "the name is used with different frames",
+                    ));
+                }
+            }
+            previous = Some(keyframes);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn write_rule(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            GlobalRule::Keyframes(x) => x.write_rule(stream),
+        }
+    }
+}
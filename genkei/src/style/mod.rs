@@ -1,15 +1,28 @@
 mod attribute;
 mod builder;
+mod global_rule;
+mod readable;
+mod relation;
+mod render_options;
 mod renderer;
+mod state;
 mod styles;
+mod stylesheet;
+mod tailwind;
+mod tokens;
 
 pub use attribute::*;
 pub use builder::*;
+pub use global_rule::*;
+pub use relation::*;
 pub use renderer::*;
+pub use state::*;
 pub use styles::*;
+pub use stylesheet::*;
+pub use tokens::*;
 
 use crate::{Color, ComplexColor, Str};
-use std::fmt::Write;
+use std::collections::BTreeSet;
 
 /// Represents a style.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -18,10 +31,14 @@ pub enum Style {
     Margin(Margin),
     Width(Width),
     MinWidth(MinWidth),
+    MaxWidth(MaxWidth),
     Height(Height),
     MinHeight(MinHeight),
+    MaxHeight(MaxHeight),
+    AspectRatio(AspectRatio),
     SimpleColor(ColorStyle<Color>),
     ComplexColor(ColorStyle<ComplexColor>),
+    Background(Background),
     Font(Font),
     TextAlign(TextAlign),
     Display(Display),
@@ -32,13 +49,24 @@ pub enum Style {
     Outline(Outline),
     Grid(Grid),
     Cursor(Cursor),
+    Filter(Filter),
     BackdropFilter(BackdropFilter),
     Gap(Gap),
+    Opacity(Opacity),
+    Transform(Transform),
+    Transition(Transition),
+    Animation(Animation),
+    Content(Content),
+    Container(Container),
+    Marker(Marker),
     CustomStyle(CustomStyleWrapper),
 
     State(State),
     MediaQuery(MediaQuery, Box<Style>),
+    ContainerQuery(ContainerQuery, Box<Style>),
     DataQuery(Str, Box<Style>),
+    Dark(Box<Style>),
+    Relation(Relation, Box<Style>),
 }
 
 impl Style {
@@ -48,276 +76,19 @@ impl Style {
             x => x,
         }
     }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct State {
-    pub link: bool,
-    pub visited: bool,
-    pub focus: bool,
-    pub focus_visible: bool,
-    pub hover: bool,
-    pub active: bool,
-    pub backdrop: bool,
-    pub inner: Box<Style>,
-}
-
-impl State {
-    pub fn link(style: impl Into<Style>) -> Self {
-        Self {
-            link: true,
-            visited: false,
-            focus: false,
-            focus_visible: false,
-            hover: false,
-            active: false,
-            backdrop: false,
-            inner: Box::new(style.into()),
-        }
-    }
-
-    pub fn visited(style: impl Into<Style>) -> Self {
-        Self {
-            link: false,
-            visited: true,
-            focus: false,
-            focus_visible: false,
-            hover: false,
-            active: false,
-            backdrop: false,
-            inner: Box::new(style.into()),
-        }
-    }
-
-    pub fn focus(style: impl Into<Style>) -> Self {
-        Self {
-            link: false,
-            visited: false,
-            focus: true,
-            focus_visible: false,
-            hover: false,
-            active: false,
-            backdrop: false,
-            inner: Box::new(style.into()),
-        }
-    }
-
-    pub fn focus_visible(style: impl Into<Style>) -> Self {
-        Self {
-            link: false,
-            visited: false,
-            focus: false,
-            focus_visible: true,
-            hover: false,
-            active: false,
-            backdrop: false,
-            inner: Box::new(style.into()),
-        }
-    }
-
-    pub fn hover(style: impl Into<Style>) -> Self {
-        Self {
-            link: false,
-            visited: false,
-            focus: false,
-            focus_visible: false,
-            hover: true,
-            active: false,
-            backdrop: false,
-            inner: Box::new(style.into()),
-        }
-    }
-
-    pub fn active(style: impl Into<Style>) -> Self {
-        Self {
-            link: false,
-            visited: false,
-            focus: false,
-            focus_visible: false,
-            hover: false,
-            active: true,
-            backdrop: false,
-            inner: Box::new(style.into()),
-        }
-    }
 
-    pub fn backdrop(style: impl Into<Style>) -> Self {
-        Self {
-            link: false,
-            visited: false,
-            focus: false,
-            focus_visible: false,
-            hover: false,
-            active: false,
-            backdrop: true,
-            inner: Box::new(style.into()),
-        }
-    }
-
-    pub fn simplify(self) -> Self {
-        let inner = self.inner.simplify();
-
-        match inner {
-            Style::State(x) => Self {
-                link: self.link || x.link,
-                visited: self.visited || x.visited,
-                focus: self.focus || x.focus,
-                focus_visible: self.focus_visible || x.focus_visible,
-                hover: self.hover || x.hover,
-                active: self.active || x.active,
-                backdrop: self.backdrop || x.backdrop,
-                inner: x.inner,
-            },
-            _ => Self {
-                link: self.link,
-                visited: self.visited,
-                focus: self.focus,
-                focus_visible: self.focus_visible,
-                hover: self.hover,
-                active: self.active,
-                backdrop: self.backdrop,
-                inner: Box::new(inner),
-            },
-        }
-    }
-
-    pub(crate) fn write_selector_prefix(&self, stream: &mut String) -> Result<(), StyleError> {
-        if self.link {
-            write!(stream, "link\\:")?;
-        }
-        if self.visited {
-            write!(stream, "visited\\:")?;
-        }
-        if self.focus {
-            write!(stream, "focus\\:")?;
-        }
-        if self.focus_visible {
-            write!(stream, "focus-visible\\:")?;
-        }
-        if self.hover {
-            write!(stream, "hover\\:")?;
-        }
-        if self.active {
-            write!(stream, "active\\:")?;
-        }
-        if self.backdrop {
-            write!(stream, "backdrop\\:")?;
-        }
-
-        Ok(())
-    }
-
-    pub(crate) fn write_selector_suffix(&self, stream: &mut String) -> Result<(), StyleError> {
-        if self.link {
-            write!(stream, ":link")?;
-        }
-        if self.visited {
-            write!(stream, ":visited")?;
-        }
-        if self.focus {
-            write!(stream, ":focus")?;
-        }
-        if self.focus_visible {
-            write!(stream, ":focus-visible")?;
-        }
-        if self.hover {
-            write!(stream, ":hover")?;
-        }
-        if self.active {
-            write!(stream, ":active")?;
-        }
-        if self.backdrop {
-            write!(stream, "::backdrop")?;
-        }
-
-        Ok(())
-    }
-
-    pub(crate) fn write_classname_prefix(&self, stream: &mut String) -> Result<(), StyleError> {
-        if self.link {
-            write!(stream, "link:")?;
-        }
-        if self.visited {
-            write!(stream, "visited:")?;
-        }
-        if self.focus {
-            write!(stream, "focus:")?;
-        }
-        if self.focus_visible {
-            write!(stream, "focus-visible:")?;
-        }
-        if self.hover {
-            write!(stream, "hover:")?;
-        }
-        if self.active {
-            write!(stream, "active:")?;
-        }
-        if self.backdrop {
-            write!(stream, "backdrop:")?;
-        }
-
-        Ok(())
-    }
-}
-
-impl From<State> for Style {
-    fn from(value: State) -> Self {
-        Style::State(value)
-    }
-}
-
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        // Compare 'link'
-        match self.link.cmp(&other.link) {
-            std::cmp::Ordering::Equal => {}
-            x => return x,
-        }
-
-        // Compare 'visited'
-        match self.visited.cmp(&other.visited) {
-            std::cmp::Ordering::Equal => {}
-            x => return x,
-        }
-
-        // Compare 'focus'
-        match self.focus.cmp(&other.focus) {
-            std::cmp::Ordering::Equal => {}
-            x => return x,
-        }
-
-        // Compare 'focus_visible'
-        match self.focus_visible.cmp(&other.focus_visible) {
-            std::cmp::Ordering::Equal => {}
-            x => return x,
-        }
-
-        // Compare 'hover'
-        match self.hover.cmp(&other.hover) {
-            std::cmp::Ordering::Equal => {}
-            x => return x,
-        }
-
-        // Compare 'active'
-        match self.active.cmp(&other.active) {
-            std::cmp::Ordering::Equal => {}
-            x => return x,
-        }
-
-        // Compare 'backdrop'
-        match self.backdrop.cmp(&other.backdrop) {
-            std::cmp::Ordering::Equal => {}
-            x => return x,
+    /// Collects the global rules, e.g. `@keyframes`, this style depends on.
+    pub(crate) fn collect_global_rules(&self, rules: &mut BTreeSet<GlobalRule>) {
+        match self {
+            Style::Animation(x) => x.collect_global_rules(rules),
+            Style::State(x) => x.inner.collect_global_rules(rules),
+            Style::MediaQuery(_, x) => x.collect_global_rules(rules),
+            Style::ContainerQuery(_, x) => x.collect_global_rules(rules),
+            Style::DataQuery(_, x) => x.collect_global_rules(rules),
+            Style::Dark(x) => x.collect_global_rules(rules),
+            Style::Relation(_, x) => x.collect_global_rules(rules),
+            _ => {}
         }
-
-        // Compare 'inner'
-        self.inner.cmp(&other.inner)
     }
 }
 
@@ -0,0 +1,82 @@
+/// Writes minified css with one declaration per line and nested blocks indented by two spaces,
+/// e.g. `.p-4{padding:1rem}` becomes `.p-4 {\n  padding: 1rem;\n}\n`.
+///
+/// Escaped characters, strings and parentheses are kept as they are, so e.g. `\:` in a
+/// selector or `;` in a `url(..)` doesn't end a segment.
+pub(crate) fn write_readable_css(stream: &mut String, css: &str) {
+    let mut depth = 0;
+    let mut parens = 0;
+    let mut quote = None;
+    let mut segment = String::with_capacity(64);
+    let mut chars = css.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (_, '\\') => {
+                segment.push(c);
+                segment.extend(chars.next());
+                continue;
+            }
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => parens += 1,
+            (None, ')') => parens -= 1,
+            (None, '{') if parens == 0 => {
+                write_indent(stream, depth);
+                stream.push_str(segment.trim());
+                stream.push_str(" {\n");
+                segment.clear();
+                depth += 1;
+                continue;
+            }
+            (None, ';') if parens == 0 => {
+                write_declaration(stream, depth, &segment);
+                segment.clear();
+                continue;
+            }
+            (None, '}') if parens == 0 => {
+                write_declaration(stream, depth, &segment);
+                segment.clear();
+                depth = depth.saturating_sub(1);
+                write_indent(stream, depth);
+                stream.push_str("}\n");
+                continue;
+            }
+            _ => {}
+        }
+        segment.push(c);
+    }
+    write_declaration(stream, depth, &segment);
+}
+
+/// Writes a css comment on its own line, `*/` in the text is broken up.
+pub(crate) fn write_css_comment(stream: &mut String, text: &str) {
+    stream.push_str("/* ");
+    stream.push_str(&text.replace("*/", "* /"));
+    stream.push_str(" */\n");
+}
+
+/// Writes `property: value;`, statements like `@import ..;` are written as they are.
+fn write_declaration(stream: &mut String, depth: usize, declaration: &str) {
+    let declaration = declaration.trim();
+    if declaration.is_empty() {
+        return;
+    }
+
+    write_indent(stream, depth);
+    match declaration.split_once(':') {
+        Some((property, value)) if !declaration.starts_with('@') => {
+            stream.push_str(property.trim_end());
+            stream.push_str(": ");
+            stream.push_str(value.trim());
+        }
+        _ => stream.push_str(declaration),
+    }
+    stream.push_str(";\n");
+}
+
+fn write_indent(stream: &mut String, depth: usize) {
+    for _ in 0..depth {
+        stream.push_str("  ");
+    }
+}
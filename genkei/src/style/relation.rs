@@ -0,0 +1,111 @@
+use crate::{StateVariant, Str, Style, StyleError};
+use std::fmt::Write;
+
+/// Whether a [`Relation`] depends on an ancestor or a preceding sibling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RelationKind {
+    /// An ancestor marked with `group`.
+    Group,
+    /// A preceding sibling marked with `peer`.
+    Peer,
+}
+
+impl RelationKind {
+    fn name(&self) -> &'static str {
+        match self {
+            RelationKind::Group => "group",
+            RelationKind::Peer => "peer",
+        }
+    }
+}
+
+/// Marks an element as a `group` or `peer`, e.g. `group/item`. The marker only adds a class
+/// name, it doesn't render any css.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Marker {
+    pub kind: RelationKind,
+    pub name: Option<Str>,
+}
+
+impl From<Marker> for Style {
+    fn from(value: Marker) -> Self {
+        Style::Marker(value)
+    }
+}
+
+impl Marker {
+    pub fn new(kind: RelationKind, name: Option<Str>) -> Self {
+        Self { kind, name }
+    }
+
+    /// Writes `/name` if the marker is named. The name is part of the class attribute and of
+    /// the selectors, so it must be a css name.
+    fn write_name(&self, stream: &mut String) -> Result<(), StyleError> {
+        if let Some(name) = &self.name {
+            if !super::renderer::is_css_name(name) {
+                return Err(StyleError::InvalidName(
+                    name.to_string(),
+                    "a marker name may only contain letters, digits, `-` and `_`",
+                ));
+            }
+            write!(stream, "/{}", name)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        write!(stream, "{}", self.kind.name())?;
+        self.write_name(stream)?;
+
+        Ok(())
+    }
+}
+
+/// A style that applies when a marked ancestor or preceding sibling is in the given state,
+/// e.g. `group-hover/item:p-2` renders as `.group\/item:hover .group-hover\/item\:p-2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Relation {
+    pub marker: Marker,
+    pub variant: StateVariant,
+}
+
+impl Relation {
+    pub fn new(marker: Marker, variant: StateVariant) -> Self {
+        Self { marker, variant }
+    }
+
+    pub(crate) fn write_classname_prefix(&self, stream: &mut String) -> Result<(), StyleError> {
+        write!(
+            stream,
+            "{}-{}",
+            self.marker.kind.name(),
+            self.variant.name()
+        )?;
+        self.marker.write_name(stream)?;
+        stream.push(':');
+
+        Ok(())
+    }
+
+    /// Returns whether the marked element is a preceding sibling, i.e. the selector is joined
+    /// with `~` and has to follow the ancestor selectors.
+    pub(crate) fn is_sibling(&self) -> bool {
+        self.marker.kind == RelationKind::Peer
+    }
+
+    /// Writes the selector of the marked element including the combinator, e.g. `.group:hover `.
+    pub(crate) fn write_selector_prefix(&self, stream: &mut String) -> Result<(), StyleError> {
+        let mut classname = String::with_capacity(16);
+        self.marker.write_classname(&mut classname)?;
+        stream.push('.');
+        super::renderer::write_escaped_classname(stream, &classname)?;
+        stream.push_str(self.variant.selector());
+        match self.marker.kind {
+            RelationKind::Group => stream.push(' '),
+            RelationKind::Peer => stream.push('~'),
+        }
+
+        Ok(())
+    }
+}
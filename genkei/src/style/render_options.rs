@@ -0,0 +1,150 @@
+use super::tokens::SPACING_SCALE;
+use crate::{
+    Breakpoint, ClassNaming, Color, ColorSyntax, DarkMode, DropShadow, FontFamily, FontSize,
+    Palette, StyleError, StyleOptions,
+};
+
+/// The style options the renderer writes with. Spacing values reference the design tokens if
+/// the options enable them, and colors outside of the sRGB gamut are written as
+/// `color(display-p3 ..)` while the renderer writes the `@supports` fallback rules. Everything
+/// else is forwarded to the wrapped options.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RenderOptions<O: StyleOptions> {
+    pub options: O,
+    pub display_p3: bool,
+}
+
+impl<O: StyleOptions> From<O> for RenderOptions<O> {
+    fn from(options: O) -> Self {
+        Self {
+            options,
+            display_p3: false,
+        }
+    }
+}
+
+impl<O: StyleOptions> StyleOptions for RenderOptions<O> {
+    fn spacing<Stream>(&self, stream: &mut Stream, value: i32) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        if !self.options.design_tokens() {
+            self.options.spacing(stream, value)
+        } else if SPACING_SCALE.contains(&value) {
+            write!(stream, "var(--spacing-{})", value)?;
+            Ok(())
+        } else {
+            write!(stream, "calc(var(--spacing-1)*{})", value)?;
+            Ok(())
+        }
+    }
+
+    fn percentage<Stream>(&self, stream: &mut Stream, x: i32, y: i32) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        self.options.percentage(stream, x, y)
+    }
+
+    fn border<Stream>(&self, stream: &mut Stream, value: i32) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        self.options.border(stream, value)
+    }
+
+    fn font_size<Stream>(&self, stream: &mut Stream, value: i32) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        self.options.font_size(stream, value)
+    }
+
+    fn breakpoint(&self, breakpoint: Breakpoint) -> i32 {
+        self.options.breakpoint(breakpoint)
+    }
+
+    fn font_size_scale<Stream>(
+        &self,
+        stream: &mut Stream,
+        size: &FontSize,
+    ) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        self.options.font_size_scale(stream, size)
+    }
+
+    fn font_family<Stream>(
+        &self,
+        stream: &mut Stream,
+        family: &FontFamily,
+    ) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        self.options.font_family(stream, family)
+    }
+
+    fn radius<Stream>(&self, stream: &mut Stream, value: i32) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        self.options.radius(stream, value)
+    }
+
+    fn drop_shadow<Stream>(
+        &self,
+        stream: &mut Stream,
+        shadow: &DropShadow,
+    ) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        self.options.drop_shadow(stream, shadow)
+    }
+
+    fn dark_mode(&self) -> DarkMode {
+        self.options.dark_mode()
+    }
+
+    fn design_tokens(&self) -> bool {
+        self.options.design_tokens()
+    }
+
+    fn color_syntax(&self) -> ColorSyntax {
+        self.options.color_syntax()
+    }
+
+    fn class_naming(&self) -> ClassNaming {
+        self.options.class_naming()
+    }
+
+    fn display_p3_fallback(&self) -> bool {
+        self.options.display_p3_fallback()
+    }
+
+    fn readable_css(&self) -> bool {
+        self.options.readable_css()
+    }
+
+    fn palette(&self) -> &Palette {
+        self.options.palette()
+    }
+
+    fn color<Stream>(&self, stream: &mut Stream, color: &Color) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        if !self.display_p3 || color.in_srgb_gamut() {
+            self.options.color(stream, color)
+        } else {
+            write!(
+                stream,
+                "{}",
+                color.to_css_value_with(ColorSyntax::DisplayP3)?
+            )?;
+            Ok(())
+        }
+    }
+}
@@ -1,5 +1,11 @@
-use crate::Style;
-use std::collections::BTreeSet;
+use super::readable::{write_css_comment, write_readable_css};
+use super::render_options::RenderOptions;
+use super::tokens::write_design_tokens;
+use crate::{
+    color::EMPTY_PALETTE, Breakpoint, Color, DropShadow, FontFamily, FontSize, GlobalRule, Palette,
+    StateVariant, Style, Stylesheet,
+};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 
 /// Specifies the style options.
@@ -23,6 +29,155 @@ pub trait StyleOptions: Default {
     fn font_size<Stream>(&self, stream: &mut Stream, value: i32) -> Result<(), StyleError>
     where
         Stream: std::fmt::Write;
+
+    /// Specifies the screen width of a breakpoint in pixels.
+    fn breakpoint(&self, breakpoint: Breakpoint) -> i32 {
+        breakpoint.pixels()
+    }
+
+    /// Specifies the `font-size` and `line-height` statements of a named font size.
+    fn font_size_scale<Stream>(
+        &self,
+        stream: &mut Stream,
+        size: &FontSize,
+    ) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        write!(
+            stream,
+            "font-size:{};line-height:{}",
+            size.font_size(),
+            size.line_height()
+        )?;
+        Ok(())
+    }
+
+    /// Specifies the font stack of a font family.
+    fn font_family<Stream>(
+        &self,
+        stream: &mut Stream,
+        family: &FontFamily,
+    ) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        write!(stream, "{}", family.to_css_value())?;
+        Ok(())
+    }
+
+    /// Specifies the border radius unit in css units, defaults to the spacing unit.
+    fn radius<Stream>(&self, stream: &mut Stream, value: i32) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        self.spacing(stream, value)
+    }
+
+    /// Specifies the `drop-shadow(..)` functions of a named shadow.
+    fn drop_shadow<Stream>(
+        &self,
+        stream: &mut Stream,
+        shadow: &DropShadow,
+    ) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        write!(stream, "{}", shadow.to_css_value())?;
+        Ok(())
+    }
+
+    /// Specifies how `dark:` styles are activated.
+    fn dark_mode(&self) -> DarkMode {
+        DarkMode::Media
+    }
+
+    /// Specifies whether palette colors and spacing values reference design tokens, e.g.
+    /// `var(--color-slate-500)` and `var(--spacing-4)`, instead of literal values. The tokens the
+    /// stylesheet references are defined in a `:root` block with the values of these options, so
+    /// a theme only has to override the variables.
+    fn design_tokens(&self) -> bool {
+        false
+    }
+
+    /// Specifies the syntax of color values.
+    fn color_syntax(&self) -> ColorSyntax {
+        ColorSyntax::Auto
+    }
+
+    /// Specifies the css value of a color, defaults to the [`StyleOptions::color_syntax`].
+    fn color<Stream>(&self, stream: &mut Stream, color: &Color) -> Result<(), StyleError>
+    where
+        Stream: std::fmt::Write,
+    {
+        write!(stream, "{}", color.to_css_value_with(self.color_syntax())?)?;
+        Ok(())
+    }
+
+    /// Specifies the user-defined palette colors, unregistered colors fail to render.
+    fn palette(&self) -> &Palette {
+        &EMPTY_PALETTE
+    }
+
+    /// Specifies the class names of the styles.
+    fn class_naming(&self) -> ClassNaming {
+        ClassNaming::Genkei
+    }
+
+    /// Specifies whether styles with colors outside of the sRGB gamut are repeated with
+    /// `color(display-p3 ..)` values in an `@supports` block.
+    fn display_p3_fallback(&self) -> bool {
+        false
+    }
+
+    /// Specifies whether the rendered css is readable, i.e. indented with one declaration per
+    /// line, split into commented sections and with the class name of every rule as a comment,
+    /// e.g. `/* md:p-4 */`, which also names the styles of minified class names. Meant for
+    /// development, the default is compact css for production.
+    fn readable_css(&self) -> bool {
+        false
+    }
+}
+
+/// Specifies the class names of the styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ClassNaming {
+    /// The genkei class names, e.g. `bw-2`, `br-2` and `xxl:`.
+    #[default]
+    Genkei,
+    /// Tailwind's class names, e.g. `border-2`, `rounded-lg` and `2xl:`. Styles without a
+    /// Tailwind counterpart keep their genkei class name.
+    Tailwind,
+    /// Short generated class names, e.g. `a`, `b` and `aZ`, for production pages. Every
+    /// distinct style gets the next name when it's first included, the names are stable for the
+    /// lifetime of the renderer, see [`StyleRenderer::minified_classnames`].
+    Minified,
+}
+
+/// Specifies the syntax of color values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorSyntax {
+    /// `hsl()`, `oklab()` and `oklch()` colors keep their syntax, all others are `rgb()`.
+    #[default]
+    Auto,
+    /// `rgb(r,g,b)` or `rgba(r,g,b,a)`.
+    Rgb,
+    /// `#rrggbb` or `#rrggbbaa`.
+    Hex,
+    /// `oklch(l c h)` or `oklch(l c h / a)`.
+    Oklch,
+    /// `color(display-p3 r g b)` or `color(display-p3 r g b / a)`.
+    DisplayP3,
+}
+
+/// Specifies how `dark:` styles are activated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DarkMode {
+    /// `@media (prefers-color-scheme: dark)`, follows the operating system setting.
+    #[default]
+    Media,
+    /// `.dark` ancestor selector, toggled manually by adding the `dark` class to e.g. `<html>`.
+    Class,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -76,6 +231,17 @@ pub enum StyleError {
     InlineStylingNotSupported(Style),
     /// Invalid simple color name.
     InvalidSimpleColor(String),
+    /// Invalid css color syntax, e.g. a hex color with 5 digits, and the reason.
+    InvalidColor(String, &'static str),
+    /// Two different styles render the same class name.
+    ClassnameCollision(String, Box<Style>, Box<Style>),
+    /// Invalid `@keyframes`, e.g. a name that isn't a css identifier, and the reason.
+    InvalidKeyframes(String, &'static str),
+    /// A name written into the stylesheet, e.g. of a group or peer marker, isn't valid, and
+    /// the reason.
+    InvalidName(String, &'static str),
+    /// Invalid arbitrary media query, e.g. one containing braces, and the reason.
+    InvalidMediaQuery(String, &'static str),
 }
 
 impl std::fmt::Display for StyleError {
@@ -95,6 +261,25 @@ impl std::fmt::Display for StyleError {
                 write!(f, "inline styling not supported: {:?}", style)
             }
             StyleError::InvalidSimpleColor(name) => write!(f, "invalid simple color: {}", name),
+            StyleError::InvalidColor(input, reason) => {
+                write!(f, "invalid color {:?}: {}", input, reason)
+            }
+            StyleError::ClassnameCollision(classname, first, second) => {
+                write!(
+                    f,
+                    "class name {:?} is used by {:?} and {:?}",
+                    classname, first, second
+                )
+            }
+            StyleError::InvalidKeyframes(name, reason) => {
+                write!(f, "invalid keyframes {:?}: {}", name, reason)
+            }
+            StyleError::InvalidName(name, reason) => {
+                write!(f, "invalid name {:?}: {}", name, reason)
+            }
+            StyleError::InvalidMediaQuery(query, reason) => {
+                write!(f, "invalid media query {:?}: {}", query, reason)
+            }
         }
     }
 }
@@ -110,10 +295,14 @@ impl From<std::fmt::Error> for StyleError {
 /// A renderer for styles. This renderer is used to generate css from styles.
 #[derive(Debug, Clone)]
 pub struct StyleRenderer<Opt: StyleOptions> {
-    options: Opt,
+    options: RenderOptions<Opt>,
     capacity: usize,
     include_css_reset: bool,
     styles: BTreeSet<Style>,
+    global_rules: BTreeSet<GlobalRule>,
+    classnames: BTreeMap<String, Style>,
+    minified: BTreeMap<Style, String>,
+    next_minified: usize,
 }
 
 impl<Opt: StyleOptions> StyleRenderer<Opt> {
@@ -123,27 +312,35 @@ impl<Opt: StyleOptions> StyleRenderer<Opt> {
 
     pub fn with_options(options: Opt, include_css_reset: bool, capacity: usize) -> Self {
         Self {
-            options,
+            options: options.into(),
             capacity,
             include_css_reset,
             styles: BTreeSet::new(),
+            global_rules: BTreeSet::new(),
+            classnames: BTreeMap::new(),
+            minified: BTreeMap::new(),
+            next_minified: 0,
         }
     }
 
-    fn write_css_statement(
+    fn write_css_statement<O: StyleOptions>(
         style: &Style,
         stream: &mut String,
-        options: &Opt,
+        options: &O,
     ) -> Result<(), StyleError> {
         match style {
             Style::Padding(x) => x.write_css_statement(stream, options)?,
             Style::Margin(x) => x.write_css_statement(stream, options)?,
             Style::Width(x) => x.write_css_statement(stream, options)?,
             Style::MinWidth(x) => x.write_css_statement(stream, options)?,
+            Style::MaxWidth(x) => x.write_css_statement(stream, options)?,
             Style::Height(x) => x.write_css_statement(stream, options)?,
             Style::MinHeight(x) => x.write_css_statement(stream, options)?,
+            Style::MaxHeight(x) => x.write_css_statement(stream, options)?,
+            Style::AspectRatio(x) => x.write_css_statement(stream, options)?,
             Style::SimpleColor(x) => x.write_css_statement(stream, options)?,
             Style::ComplexColor(x) => x.write_css_statement(stream, options)?,
+            Style::Background(x) => x.write_css_statement(stream, options)?,
             Style::Font(x) => x.write_css_statement(stream, options)?,
             Style::TextAlign(x) => x.write_css_statement(stream, options)?,
             Style::Display(x) => x.write_css_statement(stream, options)?,
@@ -154,79 +351,167 @@ impl<Opt: StyleOptions> StyleRenderer<Opt> {
             Style::Outline(x) => x.write_css_statement(stream, options)?,
             Style::Grid(x) => x.write_css_statement(stream, options)?,
             Style::Cursor(x) => x.write_css_statement(stream, options)?,
+            Style::Filter(x) => x.write_css_statement(stream, options)?,
             Style::BackdropFilter(x) => x.write_css_statement(stream, options)?,
             Style::Gap(x) => x.write_css_statement(stream, options)?,
+            Style::Opacity(x) => x.write_css_statement(stream, options)?,
+            Style::Transform(x) => x.write_css_statement(stream, options)?,
+            Style::Transition(x) => x.write_css_statement(stream, options)?,
+            Style::Animation(x) => x.write_css_statement(stream, options)?,
+            Style::Content(x) => x.write_css_statement(stream, options)?,
+            Style::Container(x) => x.write_css_statement(stream, options)?,
 
-            Style::State(x) => Self::write_css_statement(&x.inner, stream, options)?,
+            Style::State(x) => {
+                if x.generates_content() && !matches!(*x.inner, Style::Content(_)) {
+                    write!(stream, "content:var(--tw-content,'');")?;
+                }
+                Self::write_css_statement(&x.inner, stream, options)?
+            }
             Style::MediaQuery(_, x) => Self::write_css_statement(x, stream, options)?,
+            Style::ContainerQuery(_, x) => Self::write_css_statement(x, stream, options)?,
             Style::DataQuery(_, x) => Self::write_css_statement(x, stream, options)?,
+            Style::Dark(x) => Self::write_css_statement(x, stream, options)?,
+            Style::Relation(_, x) => Self::write_css_statement(x, stream, options)?,
             _ => return Err(StyleError::CssStatementUnsupported(style.clone())),
         }
 
         Ok(())
     }
 
-    fn write_css_selector(style: &Style, stream: &mut String) -> Result<(), StyleError> {
-        match style {
-            Style::Padding(x) => x.write_classname(stream)?,
-            Style::Margin(x) => x.write_classname(stream)?,
-            Style::Width(x) => x.write_classname(stream)?,
-            Style::MinWidth(x) => x.write_classname(stream)?,
-            Style::Height(x) => x.write_classname(stream)?,
-            Style::MinHeight(x) => x.write_classname(stream)?,
-            Style::SimpleColor(x) => x.write_classname(stream)?,
-            Style::ComplexColor(x) => x.write_classname(stream)?,
-            Style::Font(x) => x.write_classname(stream)?,
-            Style::TextAlign(x) => x.write_classname(stream)?,
-            Style::Display(x) => x.write_classname(stream)?,
-            Style::Flex(x) => x.write_classname(stream)?,
-            Style::Justify(x) => x.write_classname(stream)?,
-            Style::Align(x) => x.write_classname(stream)?,
-            Style::Border(x) => x.write_classname(stream)?,
-            Style::Outline(x) => x.write_classname(stream)?,
-            Style::Grid(x) => x.write_classname(stream)?,
-            Style::Cursor(x) => x.write_classname(stream)?,
-            Style::BackdropFilter(x) => x.write_classname(stream)?,
-            Style::Gap(x) => x.write_classname(stream)?,
+    fn write_css_selector(
+        style: &Style,
+        classname: &str,
+        stream: &mut String,
+    ) -> Result<(), StyleError> {
+        write_escaped_classname(stream, classname)?;
+        Self::write_css_selector_suffix(style, stream)?;
+        Ok(())
+    }
+
+    fn write_css_selector_suffix(style: &Style, stream: &mut String) -> Result<(), StyleError> {
+        let mut pseudo_element = None;
+        Self::write_css_selector_pseudo_classes(style, stream, &mut pseudo_element)?;
+        // a pseudo-element has to come last
+        if let Some(pseudo_element) = pseudo_element {
+            stream.push_str(pseudo_element.selector());
+        }
+
+        Ok(())
+    }
 
+    fn write_css_selector_pseudo_classes(
+        style: &Style,
+        stream: &mut String,
+        pseudo_element: &mut Option<StateVariant>,
+    ) -> Result<(), StyleError> {
+        match style {
             Style::State(x) => {
-                x.write_selector_prefix(stream)?;
-                Self::write_css_selector(&x.inner, stream)?;
-                x.write_selector_suffix(stream)?;
+                Self::write_css_selector_pseudo_classes(&x.inner, stream, pseudo_element)?;
+                x.write_selector_suffix(stream, pseudo_element)?;
             }
-            Style::MediaQuery(mq, inner) => {
-                mq.write_classname(stream)?;
-                stream.push_str("\\:");
-                Self::write_css_selector(inner, stream)?;
+            Style::MediaQuery(_, inner) | Style::ContainerQuery(_, inner) => {
+                Self::write_css_selector_pseudo_classes(inner, stream, pseudo_element)?
+            }
+            Style::Dark(inner) | Style::Relation(_, inner) => {
+                Self::write_css_selector_pseudo_classes(inner, stream, pseudo_element)?
             }
             Style::DataQuery(dq, inner) => {
-                write_data_query_prefix(stream, dq)?;
-                stream.push_str("\\:");
-                Self::write_css_selector(inner, stream)?;
+                Self::write_css_selector_pseudo_classes(inner, stream, pseudo_element)?;
                 write_data_query_suffix(stream, dq)?;
             }
-            _ => return Err(StyleError::CssSelectorUnsupported(style.clone())),
+            _ => {}
         }
 
         Ok(())
     }
 
-    fn write_css_selector_root(style: &Style, stream: &mut String) -> Result<(), StyleError> {
+    fn write_css_selector_root<O: StyleOptions>(
+        style: &Style,
+        classname: &str,
+        stream: &mut String,
+        options: &O,
+    ) -> Result<(), StyleError> {
+        Self::write_css_selector_ancestors(style, stream, options)?;
         stream.push('.');
-        Self::write_css_selector(style, stream)?;
+        Self::write_css_selector(style, classname, stream)?;
         Ok(())
     }
 
-    fn write_classname(style: &Style, stream: &mut String) -> Result<(), StyleError> {
+    /// Writes the ancestor and sibling selectors, e.g. `.dark ` or `.peer:checked~`, the
+    /// style depends on.
+    fn write_css_selector_ancestors<O: StyleOptions>(
+        style: &Style,
+        stream: &mut String,
+        options: &O,
+    ) -> Result<(), StyleError> {
+        // the sibling steps come last, e.g. `.dark .peer:checked~`, the ancestors apply to the
+        // peer and the element alike
+        let mut siblings = String::new();
+        Self::write_css_selector_contexts(style, stream, &mut siblings, options)?;
+        stream.push_str(&siblings);
+        Ok(())
+    }
+
+    fn write_css_selector_contexts<O: StyleOptions>(
+        style: &Style,
+        ancestors: &mut String,
+        siblings: &mut String,
+        options: &O,
+    ) -> Result<(), StyleError> {
+        match style {
+            Style::State(x) => {
+                Self::write_css_selector_contexts(&x.inner, ancestors, siblings, options)?
+            }
+            Style::MediaQuery(_, inner) | Style::ContainerQuery(_, inner) => {
+                Self::write_css_selector_contexts(inner, ancestors, siblings, options)?
+            }
+            Style::DataQuery(_, inner) => {
+                Self::write_css_selector_contexts(inner, ancestors, siblings, options)?
+            }
+            Style::Dark(inner) => {
+                if options.dark_mode() == DarkMode::Class {
+                    ancestors.push_str(".dark ");
+                }
+                Self::write_css_selector_contexts(inner, ancestors, siblings, options)?;
+            }
+            Style::Relation(relation, inner) => {
+                if relation.is_sibling() {
+                    relation.write_selector_prefix(siblings)?;
+                } else {
+                    relation.write_selector_prefix(ancestors)?;
+                }
+                Self::write_css_selector_contexts(inner, ancestors, siblings, options)?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn write_classname<O: StyleOptions>(
+        style: &Style,
+        stream: &mut String,
+        options: &O,
+    ) -> Result<(), StyleError> {
+        if options.class_naming() == ClassNaming::Tailwind
+            && super::tailwind::write_classname(style, stream, options)?
+        {
+            return Ok(());
+        }
+
         match style {
             Style::Padding(x) => x.write_classname(stream)?,
             Style::Margin(x) => x.write_classname(stream)?,
             Style::Width(x) => x.write_classname(stream)?,
             Style::MinWidth(x) => x.write_classname(stream)?,
+            Style::MaxWidth(x) => x.write_classname(stream)?,
             Style::Height(x) => x.write_classname(stream)?,
             Style::MinHeight(x) => x.write_classname(stream)?,
+            Style::MaxHeight(x) => x.write_classname(stream)?,
+            Style::AspectRatio(x) => x.write_classname(stream)?,
             Style::SimpleColor(x) => x.write_classname(stream)?,
             Style::ComplexColor(x) => x.write_classname(stream)?,
+            Style::Background(x) => x.write_classname(stream)?,
             Style::Font(x) => x.write_classname(stream)?,
             Style::TextAlign(x) => x.write_classname(stream)?,
             Style::Display(x) => x.write_classname(stream)?,
@@ -237,22 +522,50 @@ impl<Opt: StyleOptions> StyleRenderer<Opt> {
             Style::Outline(x) => x.write_classname(stream)?,
             Style::Grid(x) => x.write_classname(stream)?,
             Style::Cursor(x) => x.write_classname(stream)?,
+            Style::Filter(x) => x.write_classname(stream)?,
             Style::BackdropFilter(x) => x.write_classname(stream)?,
             Style::Gap(x) => x.write_classname(stream)?,
+            Style::Opacity(x) => x.write_classname(stream)?,
+            Style::Transform(x) => x.write_classname(stream)?,
+            Style::Transition(x) => x.write_classname(stream)?,
+            Style::Animation(x) => x.write_classname(stream)?,
+            Style::Content(x) => x.write_classname(stream)?,
+            Style::Container(x) => x.write_classname(stream)?,
+            Style::Marker(x) => x.write_classname(stream)?,
 
             Style::State(x) => {
                 x.write_classname_prefix(stream)?;
-                Self::write_css_selector(&x.inner, stream)?;
+                Self::write_classname(&x.inner, stream, options)?;
             }
             Style::MediaQuery(mq, inner) => {
-                mq.write_classname(stream)?;
+                match options.class_naming() {
+                    ClassNaming::Tailwind => super::tailwind::write_media_query(mq, stream)?,
+                    ClassNaming::Genkei | ClassNaming::Minified => mq.write_classname(stream)?,
+                }
                 stream.push(':');
-                Self::write_css_selector(inner, stream)?;
+                Self::write_classname(inner, stream, options)?;
+            }
+            Style::ContainerQuery(cq, inner) => {
+                cq.write_classname(stream)?;
+                stream.push(':');
+                Self::write_classname(inner, stream, options)?;
             }
             Style::DataQuery(dq, inner) => {
                 write_data_query_classname(stream, dq)?;
                 stream.push(':');
-                Self::write_css_selector(inner, stream)?;
+                Self::write_classname(inner, stream, options)?;
+            }
+            Style::Dark(inner) => {
+                stream.push_str("dark:");
+                Self::write_classname(inner, stream, options)?;
+            }
+            Style::Relation(relation, inner) => {
+                // the marked element is matched by its state, it has no pseudo-elements
+                if relation.variant.is_pseudo_element() {
+                    return Err(StyleError::CssSelectorUnsupported(style.clone()));
+                }
+                relation.write_classname_prefix(stream)?;
+                Self::write_classname(inner, stream, options)?;
             }
             _ => return Err(StyleError::CssClassnameUnsupported(style.clone())),
         }
@@ -260,36 +573,80 @@ impl<Opt: StyleOptions> StyleRenderer<Opt> {
         Ok(())
     }
 
-    fn write_style(style: &Style, stream: &mut String, options: &Opt) -> Result<(), StyleError> {
+    fn write_style<O: StyleOptions>(
+        style: &Style,
+        classname: &str,
+        stream: &mut String,
+        options: &O,
+    ) -> Result<(), StyleError> {
+        // TODO: media-queries can be grouped as an optimization.
+        let at_rules = Self::write_at_rules(style, stream, options)?;
+        Self::write_css_selector_root(style, classname, stream, options)?;
+        stream.push('{');
+        Self::write_css_statement(style, stream, options)?;
+        stream.push('}');
+        for _ in 0..at_rules {
+            stream.push('}');
+        }
+
+        Ok(())
+    }
+
+    /// Opens the at-rules, e.g. `@media`, the style is nested in and returns how many were
+    /// opened.
+    fn write_at_rules<O: StyleOptions>(
+        style: &Style,
+        stream: &mut String,
+        options: &O,
+    ) -> Result<usize, StyleError> {
         match style {
-            Style::MediaQuery(mq, _) => {
-                // TODO: media-queries can be grouped as an optimization.
-                mq.write_selector(stream)?;
-                stream.push('{');
-                Self::write_css_selector_root(style, stream)?;
+            Style::State(x) => Self::write_at_rules(&x.inner, stream, options),
+            Style::MediaQuery(mq, inner) => {
+                mq.write_selector(stream, options)?;
                 stream.push('{');
-                Self::write_css_statement(style, stream, options)?;
-                stream.push('}');
-                stream.push('}');
+                Ok(1 + Self::write_at_rules(inner, stream, options)?)
             }
-            style => {
-                Self::write_css_selector_root(style, stream)?;
+            Style::ContainerQuery(cq, inner) => {
+                cq.write_selector(stream)?;
                 stream.push('{');
-                Self::write_css_statement(style, stream, options)?;
-                stream.push('}');
+                Ok(1 + Self::write_at_rules(inner, stream, options)?)
             }
+            Style::DataQuery(_, inner) => Self::write_at_rules(inner, stream, options),
+            Style::Dark(inner) => match options.dark_mode() {
+                DarkMode::Media => {
+                    stream.push_str("@media(prefers-color-scheme:dark){");
+                    Ok(1 + Self::write_at_rules(inner, stream, options)?)
+                }
+                DarkMode::Class => Self::write_at_rules(inner, stream, options),
+            },
+            Style::Relation(_, inner) => Self::write_at_rules(inner, stream, options),
+            _ => Ok(0),
         }
-
-        Ok(())
     }
 
     pub fn include_style(&mut self, style: impl Into<Style>) {
-        self.styles.insert(style.into());
+        let style = style.into();
+        style.collect_global_rules(&mut self.global_rules);
+        // markers keep their class names, relations select them by name
+        if self.options.class_naming() == ClassNaming::Minified
+            && !matches!(style, Style::Marker(_))
+            && !self.minified.contains_key(&style)
+        {
+            let classname = loop {
+                let classname = minified_classname(self.next_minified);
+                self.next_minified += 1;
+                if !RESERVED_CLASSNAMES.contains(&classname.as_str()) {
+                    break classname;
+                }
+            };
+            self.minified.insert(style.clone(), classname);
+        }
+        self.styles.insert(style);
     }
 
     pub fn include_styles(&mut self, styles: impl IntoIterator<Item = impl Into<Style>>) {
         for style in styles {
-            self.styles.insert(style.into());
+            self.include_style(style);
         }
     }
 
@@ -299,19 +656,43 @@ impl<Opt: StyleOptions> StyleRenderer<Opt> {
             if !buffer.is_empty() {
                 buffer.push(' ');
             }
-            Self::write_classname(&style, &mut buffer)?;
-            self.include_style(style);
+            let start = buffer.len();
+            Self::write_classname(&style, &mut buffer, &self.options)?;
+            track_classname(&mut self.classnames, &buffer[start..], &style)?;
+            self.include_style(style.clone());
+            if let Some(classname) = self.minified.get(&style) {
+                buffer.truncate(start);
+                buffer.push_str(classname);
+            }
         }
 
         Ok(buffer)
     }
 
+    /// Returns the generated class names of [`ClassNaming::Minified`] with the genkei class
+    /// names they stand for, e.g. `a` → `hover:p-4`, for debugging. It's empty in the other
+    /// naming modes.
+    pub fn minified_classnames(&self) -> Result<BTreeMap<String, String>, StyleError> {
+        let mut mapping = BTreeMap::new();
+        for (style, minified) in &self.minified {
+            let mut classname = String::with_capacity(64);
+            Self::write_classname(style, &mut classname, &self.options)?;
+            mapping.insert(minified.clone(), classname);
+        }
+
+        Ok(mapping)
+    }
+
     pub fn use_as_inline_style(&mut self, styles: BTreeSet<Style>) -> Result<String, StyleError> {
         let mut buffer = String::with_capacity(128);
         for style in styles {
             match style {
                 Style::State(_) => return Err(StyleError::InlineStylingNotSupported(style)),
-                Style::MediaQuery(_, _) => {
+                Style::MediaQuery(_, _)
+                | Style::ContainerQuery(_, _)
+                | Style::Dark(_)
+                | Style::Marker(_)
+                | Style::Relation(_, _) => {
                     return Err(StyleError::InlineStylingNotSupported(style))
                 }
                 _ => {
@@ -319,6 +700,7 @@ impl<Opt: StyleOptions> StyleRenderer<Opt> {
                         buffer.push(';');
                     }
                     Self::write_css_statement(&style, &mut buffer, &self.options)?;
+                    style.collect_global_rules(&mut self.global_rules);
                 }
             }
         }
@@ -331,22 +713,122 @@ impl<Opt: StyleOptions> StyleRenderer<Opt> {
             styles,
             capacity,
             include_css_reset,
-            options,
+            mut options,
+            global_rules,
+            mut classnames,
+            minified,
             ..
         } = self;
 
+        GlobalRule::check_conflicts(&global_rules)?;
+
+        // styles added by `include_style` haven't been checked yet
+        let mut selectors = Vec::with_capacity(styles.len());
+        for style in &styles {
+            let mut classname = String::with_capacity(64);
+            Self::write_classname(style, &mut classname, &options)?;
+            track_classname(&mut classnames, &classname, style)?;
+            selectors.push(minified.get(style).cloned().unwrap_or(classname));
+        }
+
+        let readable = options.readable_css();
         let mut buffer = String::with_capacity(capacity);
         if include_css_reset {
-            buffer.push_str(include_str!("../../../reset.css"));
+            let reset = include_str!("../../../reset.css");
+            if readable {
+                write_css_comment(&mut buffer, "reset");
+                write_readable_css(&mut buffer, reset);
+            } else {
+                buffer.push_str(reset);
+            }
         }
 
-        for style in &styles {
-            Self::write_style(style, &mut buffer, &options)?;
+        let start = buffer.len();
+        if readable && !global_rules.is_empty() {
+            write_css_comment(&mut buffer, "global rules");
+        }
+        for rule in &global_rules {
+            let start = buffer.len();
+            rule.write_rule(&mut buffer)?;
+            if readable {
+                let rule = buffer.split_off(start);
+                write_readable_css(&mut buffer, &rule);
+            }
+        }
+
+        if readable && !styles.is_empty() {
+            write_css_comment(&mut buffer, "styles");
+        }
+        for (style, classname) in styles.iter().zip(&selectors) {
+            // markers only exist as class names for relations to select
+            if matches!(style, Style::Marker(_)) {
+                continue;
+            }
+            let start = buffer.len();
+            Self::write_style(style, classname, &mut buffer, &options)?;
+            if options.display_p3_fallback() {
+                Self::write_display_p3_fallback(style, classname, &mut buffer, &mut options)?;
+            }
+            if readable {
+                let rule = buffer.split_off(start);
+                let mut annotation = String::with_capacity(64);
+                Self::write_classname(style, &mut annotation, &options)?;
+                write_css_comment(&mut buffer, &annotation);
+                write_readable_css(&mut buffer, &rule);
+            }
+        }
+
+        // the tokens go first but only the ones the rules reference are written
+        if options.design_tokens() {
+            let rules = buffer.split_off(start);
+            let mut tokens = String::with_capacity(1024);
+            write_design_tokens(&mut tokens, &options.options, &rules)?;
+            if readable && !tokens.is_empty() {
+                write_css_comment(&mut buffer, "design tokens");
+                write_readable_css(&mut buffer, &tokens);
+            } else {
+                buffer.push_str(&tokens);
+            }
+            buffer.push_str(&rules);
         }
 
         Ok((buffer, styles))
     }
 
+    /// Renders the css as an external stylesheet named `{name}.{hash}.css`, see [`Stylesheet`].
+    pub fn render_stylesheet(
+        self,
+        name: &str,
+    ) -> Result<(Stylesheet, BTreeSet<Style>), StyleError> {
+        let (css, styles) = self.render()?;
+        Ok((Stylesheet::new(name, css), styles))
+    }
+
+    /// Repeats the style in an `@supports` block if its statement changes with display-p3
+    /// colors, i.e. it contains a color outside of the sRGB gamut.
+    fn write_display_p3_fallback(
+        style: &Style,
+        classname: &str,
+        stream: &mut String,
+        options: &mut RenderOptions<Opt>,
+    ) -> Result<(), StyleError> {
+        let mut statement = String::with_capacity(64);
+        Self::write_css_statement(style, &mut statement, options)?;
+        options.display_p3 = true;
+        let mut fallback = String::with_capacity(64);
+        let result = Self::write_css_statement(style, &mut fallback, options).and_then(|_| {
+            if statement != fallback {
+                stream.push_str("@supports(color:color(display-p3 0 0 0)){");
+                Self::write_style(style, classname, stream, options)?;
+                stream.push('}');
+            }
+            Ok(())
+        });
+        options.display_p3 = false;
+
+        result
+    }
+
     pub fn to_css(style: impl Into<Style>) -> Result<String, StyleError> {
         let mut renderer = StyleRenderer::<DefaultStyleOptions>::new(false);
         renderer.include_style(style);
@@ -354,6 +836,45 @@ impl<Opt: StyleOptions> StyleRenderer<Opt> {
     }
 }
 
+/// Class names used by the renderer itself, they're never generated for minified styles.
+const RESERVED_CLASSNAMES: [&str; 3] = ["dark", "group", "peer"];
+
+/// Returns the `index`th minified class name: `a` to `z`, `A` to `Z`, then `aa`, `ab` and so
+/// on. The names only contain letters, so they never start with a digit.
+fn minified_classname(index: usize) -> String {
+    const ALPHABET: &[u8; 52] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let mut name = Vec::with_capacity(4);
+    let mut index = index + 1;
+    while index > 0 {
+        index -= 1;
+        name.push(ALPHABET[index % ALPHABET.len()]);
+        index /= ALPHABET.len();
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+/// Records the style of a class name, a different style with the same class name fails.
+fn track_classname(
+    classnames: &mut BTreeMap<String, Style>,
+    classname: &str,
+    style: &Style,
+) -> Result<(), StyleError> {
+    match classnames.get(classname) {
+        Some(existing) if existing != style => Err(StyleError::ClassnameCollision(
+            classname.to_string(),
+            Box::new(existing.clone()),
+            Box::new(style.clone()),
+        )),
+        Some(_) => Ok(()),
+        None => {
+            classnames.insert(classname.to_string(), style.clone());
+            Ok(())
+        }
+    }
+}
+
 fn write_data_query_classname(stream: &mut String, dq: &str) -> Result<(), StyleError> {
     stream.push('[');
     stream.push_str(dq);
@@ -361,18 +882,79 @@ fn write_data_query_classname(stream: &mut String, dq: &str) -> Result<(), Style
     Ok(())
 }
 
-fn write_data_query_prefix(stream: &mut String, dq: &str) -> Result<(), StyleError> {
-    stream.push('\\');
-    stream.push('[');
-    for ch in dq.chars() {
-        // escape characters not supported in css classnames
+/// Returns whether the name only contains characters that are written unescaped in css
+/// identifiers, i.e. it can't split a class attribute or break out of a selector or value.
+pub(crate) fn is_css_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_') || !c.is_ascii())
+}
+
+/// Returns whether the name is a css identifier, i.e. a css name that doesn't start with a
+/// digit, a hyphen followed by a digit, or two hyphens.
+pub(crate) fn is_css_identifier(name: &str) -> bool {
+    let rest = name.strip_prefix('-').unwrap_or(name);
+    is_css_name(name)
+        && !rest.is_empty()
+        && !rest.starts_with(|c: char| c.is_ascii_digit() || c == '-')
+}
+
+/// Writes a free-form value as a quoted css string, e.g. for `content` and `url()`.
+pub(crate) fn write_css_string(stream: &mut String, value: &str) -> Result<(), StyleError> {
+    stream.push('"');
+    for ch in value.chars() {
         match ch {
-            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => stream.push(ch),
-            _ => write!(stream, "\\{:x}", ch as u32)?,
+            '"' | '\\' => write!(stream, "\\{}", ch)?,
+            // `<` would allow closing the surrounding `<style>` tag
+            '<' => write!(stream, "\\3c ")?,
+            ch if ch.is_control() => write!(stream, "\\{:x} ", ch as u32)?,
+            ch => stream.push(ch),
+        }
+    }
+    stream.push('"');
+    Ok(())
+}
+
+/// Writes a free-form value as part of a class name. The class attribute is split on
+/// whitespace, so spaces become `_` and literal underscores and backslashes are escaped to
+/// keep the class names unique.
+pub(crate) fn write_arbitrary_classname(
+    stream: &mut String,
+    value: &str,
+) -> Result<(), StyleError> {
+    for ch in value.chars() {
+        match ch {
+            ' ' => stream.push('_'),
+            '_' | '\\' => write!(stream, "\\{}", ch)?,
+            ch if ch.is_whitespace() || ch.is_control() => write!(stream, "\\{:x}", ch as u32)?,
+            ch => stream.push(ch),
+        }
+    }
+    Ok(())
+}
+
+/// Escapes a class name for use in a css selector.
+pub(crate) fn write_escaped_classname(
+    stream: &mut String,
+    classname: &str,
+) -> Result<(), StyleError> {
+    for (i, ch) in classname.chars().enumerate() {
+        match ch {
+            'a'..='z' | 'A'..='Z' | '-' | '_' => stream.push(ch),
+            // identifiers cannot start with a digit, or with a hyphen followed by a digit
+            '0'..='9' if i == 0 || (i == 1 && classname.starts_with('-')) => {
+                write!(stream, "\\{:x} ", ch as u32)?
+            }
+            '0'..='9' => stream.push(ch),
+            ch if !ch.is_ascii() => stream.push(ch),
+            ch if ch == ' ' || ch.is_ascii_control() => write!(stream, "\\{:x} ", ch as u32)?,
+            ch => {
+                stream.push('\\');
+                stream.push(ch);
+            }
         }
     }
-    stream.push('\\');
-    stream.push(']');
     Ok(())
 }
 
@@ -0,0 +1,274 @@
+use crate::{Style, StyleError};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// A pseudo-class or pseudo-element a [`State`] applies to.
+///
+/// The declaration order is the order of the class name prefixes and selector suffixes,
+/// pseudo-elements have to stay last to produce a valid selector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StateVariant {
+    /// :link
+    Link,
+    /// :visited
+    Visited,
+    /// :first-child
+    First,
+    /// :last-child
+    Last,
+    /// :nth-child(odd)
+    Odd,
+    /// :nth-child(even)
+    Even,
+    /// :empty
+    Empty,
+    /// :checked
+    Checked,
+    /// :required
+    Required,
+    /// :invalid
+    Invalid,
+    /// :read-only
+    ReadOnly,
+    /// :disabled
+    Disabled,
+    /// :focus-within
+    FocusWithin,
+    /// :focus
+    Focus,
+    /// :focus-visible
+    FocusVisible,
+    /// :hover
+    Hover,
+    /// :active
+    Active,
+    /// ::placeholder
+    Placeholder,
+    /// ::before
+    Before,
+    /// ::after
+    After,
+    /// ::backdrop
+    Backdrop,
+}
+
+impl StateVariant {
+    /// All variants in declaration order.
+    pub const ALL: [StateVariant; 21] = [
+        StateVariant::Link,
+        StateVariant::Visited,
+        StateVariant::First,
+        StateVariant::Last,
+        StateVariant::Odd,
+        StateVariant::Even,
+        StateVariant::Empty,
+        StateVariant::Checked,
+        StateVariant::Required,
+        StateVariant::Invalid,
+        StateVariant::ReadOnly,
+        StateVariant::Disabled,
+        StateVariant::FocusWithin,
+        StateVariant::Focus,
+        StateVariant::FocusVisible,
+        StateVariant::Hover,
+        StateVariant::Active,
+        StateVariant::Placeholder,
+        StateVariant::Before,
+        StateVariant::After,
+        StateVariant::Backdrop,
+    ];
+
+    /// Returns the class name prefix, without the trailing `:`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StateVariant::Link => "link",
+            StateVariant::Visited => "visited",
+            StateVariant::First => "first",
+            StateVariant::Last => "last",
+            StateVariant::Odd => "odd",
+            StateVariant::Even => "even",
+            StateVariant::Empty => "empty",
+            StateVariant::Checked => "checked",
+            StateVariant::Required => "required",
+            StateVariant::Invalid => "invalid",
+            StateVariant::ReadOnly => "read-only",
+            StateVariant::Disabled => "disabled",
+            StateVariant::FocusWithin => "focus-within",
+            StateVariant::Focus => "focus",
+            StateVariant::FocusVisible => "focus-visible",
+            StateVariant::Hover => "hover",
+            StateVariant::Active => "active",
+            StateVariant::Placeholder => "placeholder",
+            StateVariant::Before => "before",
+            StateVariant::After => "after",
+            StateVariant::Backdrop => "backdrop",
+        }
+    }
+
+    /// Returns whether this is a pseudo-element, a selector can only target one of them.
+    pub fn is_pseudo_element(&self) -> bool {
+        matches!(
+            self,
+            StateVariant::Placeholder
+                | StateVariant::Before
+                | StateVariant::After
+                | StateVariant::Backdrop
+        )
+    }
+
+    /// Returns the pseudo-class or pseudo-element selector.
+    pub fn selector(&self) -> &'static str {
+        match self {
+            StateVariant::Link => ":link",
+            StateVariant::Visited => ":visited",
+            StateVariant::First => ":first-child",
+            StateVariant::Last => ":last-child",
+            StateVariant::Odd => ":nth-child(odd)",
+            StateVariant::Even => ":nth-child(even)",
+            StateVariant::Empty => ":empty",
+            StateVariant::Checked => ":checked",
+            StateVariant::Required => ":required",
+            StateVariant::Invalid => ":invalid",
+            StateVariant::ReadOnly => ":read-only",
+            StateVariant::Disabled => ":disabled",
+            StateVariant::FocusWithin => ":focus-within",
+            StateVariant::Focus => ":focus",
+            StateVariant::FocusVisible => ":focus-visible",
+            StateVariant::Hover => ":hover",
+            StateVariant::Active => ":active",
+            StateVariant::Placeholder => "::placeholder",
+            StateVariant::Before => "::before",
+            StateVariant::After => "::after",
+            StateVariant::Backdrop => "::backdrop",
+        }
+    }
+}
+
+/// A style that only applies in the given states, e.g. `hover:focus:p-2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct State {
+    pub variants: BTreeSet<StateVariant>,
+    pub inner: Box<Style>,
+}
+
+impl State {
+    pub fn new(variant: StateVariant, style: impl Into<Style>) -> Self {
+        Self {
+            variants: BTreeSet::from([variant]),
+            inner: Box::new(style.into()),
+        }
+    }
+
+    pub fn link(style: impl Into<Style>) -> Self {
+        Self::new(StateVariant::Link, style)
+    }
+
+    pub fn visited(style: impl Into<Style>) -> Self {
+        Self::new(StateVariant::Visited, style)
+    }
+
+    pub fn focus(style: impl Into<Style>) -> Self {
+        Self::new(StateVariant::Focus, style)
+    }
+
+    pub fn focus_visible(style: impl Into<Style>) -> Self {
+        Self::new(StateVariant::FocusVisible, style)
+    }
+
+    pub fn hover(style: impl Into<Style>) -> Self {
+        Self::new(StateVariant::Hover, style)
+    }
+
+    pub fn active(style: impl Into<Style>) -> Self {
+        Self::new(StateVariant::Active, style)
+    }
+
+    pub fn backdrop(style: impl Into<Style>) -> Self {
+        Self::new(StateVariant::Backdrop, style)
+    }
+
+    /// Merges directly nested states into one, e.g. `hover:(focus:p-2)` into `focus:hover:p-2`.
+    pub fn simplify(self) -> Self {
+        let inner = self.inner.simplify();
+
+        match inner {
+            Style::State(x) => Self {
+                variants: self.variants.into_iter().chain(x.variants).collect(),
+                inner: x.inner,
+            },
+            _ => Self {
+                variants: self.variants,
+                inner: Box::new(inner),
+            },
+        }
+    }
+
+    /// Returns whether this state targets the `::before` or `::after` pseudo-element, which
+    /// are only rendered with a `content` property.
+    pub(crate) fn generates_content(&self) -> bool {
+        self.variants.contains(&StateVariant::Before)
+            || self.variants.contains(&StateVariant::After)
+    }
+
+    /// Writes the pseudo-classes and records the pseudo-element in `pseudo_element`, which the
+    /// renderer writes last. A second pseudo-element, e.g. `before:after:`, fails as
+    /// `::before::after` isn't a valid selector.
+    pub(crate) fn write_selector_suffix(
+        &self,
+        stream: &mut String,
+        pseudo_element: &mut Option<StateVariant>,
+    ) -> Result<(), StyleError> {
+        for variant in &self.variants {
+            if !variant.is_pseudo_element() {
+                write!(stream, "{}", variant.selector())?;
+            } else if pseudo_element.is_none() {
+                *pseudo_element = Some(*variant);
+            } else {
+                return Err(StyleError::CssSelectorUnsupported(Style::State(
+                    self.clone(),
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn write_classname_prefix(&self, stream: &mut String) -> Result<(), StyleError> {
+        for variant in &self.variants {
+            write!(stream, "{}:", variant.name())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<State> for Style {
+    fn from(value: State) -> Self {
+        Style::State(value)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // A state without a variant sorts before a state with it, variant by variant, so
+        // e.g. `hover:` rules are emitted before the more specific `focus:hover:` rules.
+        for variant in StateVariant::ALL {
+            match self
+                .variants
+                .contains(&variant)
+                .cmp(&other.variants.contains(&variant))
+            {
+                std::cmp::Ordering::Equal => {}
+                x => return x,
+            }
+        }
+
+        self.inner.cmp(&other.inner)
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{GlobalRule, Str, Style, StyleError, Styleable};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// Represents a `@keyframes` rule.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Keyframes {
+    /// The keyframes name, must be a valid css identifier.
+    pub name: Str,
+    /// The keyframe blocks, e.g. `from{opacity:0}to{opacity:1}`.
+    pub frames: Str,
+}
+
+impl Keyframes {
+    pub fn new(name: impl Into<Str>, frames: impl Into<Str>) -> Self {
+        Self {
+            name: name.into(),
+            frames: frames.into(),
+        }
+    }
+
+    /// Checks that the name is a css identifier and that the braces of the frames are
+    /// balanced, both are written into the stylesheet as they are.
+    pub(crate) fn validate(&self) -> Result<(), StyleError> {
+        let invalid = |reason| Err(StyleError::InvalidKeyframes(self.name.to_string(), reason));
+        if !crate::style::is_css_identifier(&self.name) {
+            return invalid("the name isn't a css identifier");
+        }
+        if matches!(
+            self.name.to_ascii_lowercase().as_str(),
+            "none" | "initial" | "inherit" | "unset" | "revert" | "revert-layer" | "default"
+        ) {
+            return invalid("the name is a reserved keyword");
+        }
+
+        let mut depth = 0usize;
+        for c in self.frames.chars() {
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => return invalid("the frames have unbalanced braces"),
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+        if depth != 0 {
+            return invalid("the frames have unbalanced braces");
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn write_rule(&self, stream: &mut String) -> Result<(), StyleError> {
+        self.validate()?;
+        write!(stream, "@keyframes {}{{{}}}", self.name, self.frames)?;
+        Ok(())
+    }
+}
+
+/// Represents the animation style.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Animation {
+    /// animation: none
+    None,
+    /// animation: spin 1s linear infinite
+    Spin,
+    /// animation: ping 1s cubic-bezier(0, 0, 0.2, 1) infinite
+    Ping,
+    /// animation: pulse 2s cubic-bezier(0.4, 0, 0.6, 1) infinite
+    Pulse,
+    /// animation: bounce 1s infinite
+    Bounce,
+    /// animation: name value
+    Custom(Keyframes, Str),
+}
+
+impl From<Animation> for Style {
+    fn from(value: Animation) -> Self {
+        Style::Animation(value)
+    }
+}
+
+impl Animation {
+    /// Creates a user-defined animation, e.g.
+    /// `Animation::custom("fade", "1s ease-in", "from{opacity:0}to{opacity:1}")`.
+    pub fn custom(name: impl Into<Str>, value: impl Into<Str>, frames: impl Into<Str>) -> Self {
+        Self::Custom(Keyframes::new(name, frames), value.into())
+    }
+
+    /// Returns the `@keyframes` rule this animation depends on.
+    pub fn keyframes(&self) -> Option<Keyframes> {
+        match self {
+            Animation::None => None,
+            Animation::Spin => Some(Keyframes::new("spin", "to{transform:rotate(360deg)}")),
+            Animation::Ping => Some(Keyframes::new(
+                "ping",
+                "75%,100%{transform:scale(2);opacity:0}",
+            )),
+            Animation::Pulse => Some(Keyframes::new("pulse", "50%{opacity:.5}")),
+            Animation::Bounce => Some(Keyframes::new(
+                "bounce",
+                "0%,100%{transform:translateY(-25%);animation-timing-function:cubic-bezier(0.8,0,1,1)}\
+                 50%{transform:none;animation-timing-function:cubic-bezier(0,0,0.2,1)}",
+            )),
+            Animation::Custom(keyframes, _) => Some(keyframes.clone()),
+        }
+    }
+
+    pub(crate) fn collect_global_rules(&self, rules: &mut BTreeSet<GlobalRule>) {
+        if let Some(keyframes) = self.keyframes() {
+            rules.insert(GlobalRule::Keyframes(keyframes));
+        }
+    }
+
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            Animation::None => write!(stream, "animate-none")?,
+            Animation::Spin => write!(stream, "animate-spin")?,
+            Animation::Ping => write!(stream, "animate-ping")?,
+            Animation::Pulse => write!(stream, "animate-pulse")?,
+            Animation::Bounce => write!(stream, "animate-bounce")?,
+            Animation::Custom(keyframes, _) => {
+                keyframes.validate()?;
+                write!(stream, "animate-{}", keyframes.name)?
+            }
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            Animation::None => write!(stream, "animation:none")?,
+            Animation::Spin => write!(stream, "animation:spin 1s linear infinite")?,
+            Animation::Ping => {
+                write!(stream, "animation:ping 1s cubic-bezier(0,0,0.2,1) infinite")?
+            }
+            Animation::Pulse => write!(
+                stream,
+                "animation:pulse 2s cubic-bezier(0.4,0,0.6,1) infinite"
+            )?,
+            Animation::Bounce => write!(stream, "animation:bounce 1s infinite")?,
+            Animation::Custom(keyframes, value) => {
+                write!(stream, "animation:{} {}", keyframes.name, value)?
+            }
+        };
+
+        Ok(())
+    }
+}
+
+impl<T> AnimationTrait for T where T: Styleable {}
+
+/// Animation style attributes.
+pub trait AnimationTrait: Styleable {
+    #[inline]
+    fn animate(self, value: impl Into<Animation>) -> Self::Output {
+        self.style(value.into())
+    }
+
+    #[inline]
+    fn animate_none(self) -> Self::Output {
+        self.animate(Animation::None)
+    }
+
+    #[inline]
+    fn animate_spin(self) -> Self::Output {
+        self.animate(Animation::Spin)
+    }
+
+    #[inline]
+    fn animate_ping(self) -> Self::Output {
+        self.animate(Animation::Ping)
+    }
+
+    #[inline]
+    fn animate_pulse(self) -> Self::Output {
+        self.animate(Animation::Pulse)
+    }
+
+    #[inline]
+    fn animate_bounce(self) -> Self::Output {
+        self.animate(Animation::Bounce)
+    }
+}
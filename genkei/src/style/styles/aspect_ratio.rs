@@ -0,0 +1,78 @@
+use crate::{Style, StyleError, Styleable};
+use std::fmt::Write;
+
+/// Represents the aspect-ratio style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum AspectRatio {
+    /// aspect-ratio: auto;
+    Auto,
+    /// aspect-ratio: 1 / 1;
+    Square,
+    /// aspect-ratio: 16 / 9;
+    Video,
+    /// aspect-ratio: X / Y;
+    Ratio(i32, i32),
+}
+
+impl From<AspectRatio> for Style {
+    fn from(value: AspectRatio) -> Self {
+        Style::AspectRatio(value)
+    }
+}
+
+impl AspectRatio {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            AspectRatio::Auto => write!(stream, "aspect-auto")?,
+            AspectRatio::Square => write!(stream, "aspect-square")?,
+            AspectRatio::Video => write!(stream, "aspect-video")?,
+            AspectRatio::Ratio(x, y) => write!(stream, "aspect-{}/{}", x, y)?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            AspectRatio::Auto => write!(stream, "aspect-ratio:auto")?,
+            AspectRatio::Square => write!(stream, "aspect-ratio:1/1")?,
+            AspectRatio::Video => write!(stream, "aspect-ratio:16/9")?,
+            AspectRatio::Ratio(x, y) => write!(stream, "aspect-ratio:{}/{}", x, y)?,
+        };
+
+        Ok(())
+    }
+}
+
+impl<T> AspectRatioTrait for T where T: Styleable {}
+
+/// Aspect ratio style attributes.
+pub trait AspectRatioTrait: Styleable {
+    #[inline]
+    fn aspect(self, value: impl Into<AspectRatio>) -> Self::Output {
+        self.style(value.into())
+    }
+
+    #[inline]
+    fn aspect_square(self) -> Self::Output {
+        self.aspect(AspectRatio::Square)
+    }
+
+    #[inline]
+    fn aspect_video(self) -> Self::Output {
+        self.aspect(AspectRatio::Video)
+    }
+
+    /// aspect-ratio: X / Y;
+    #[inline]
+    fn aspect_ratio(self, x: impl Into<i32>, y: impl Into<i32>) -> Self::Output {
+        self.aspect(AspectRatio::Ratio(x.into(), y.into()))
+    }
+}
@@ -1,11 +1,27 @@
-use crate::{Style, StyleError, Styleable};
+use crate::{DropShadow, Filter, Style, StyleError, Styleable};
 use std::fmt::Write;
 
 /// Represents the backdrop-filter property style.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum BackdropFilter {
-    /// backdrop-filter: blur(value)
+    /// backdrop-filter: blur(Xpx)
     Blur(i32),
+    /// backdrop-filter: brightness(X / 100)
+    Brightness(i32),
+    /// backdrop-filter: contrast(X / 100)
+    Contrast(i32),
+    /// backdrop-filter: grayscale(X%)
+    Grayscale(i32),
+    /// backdrop-filter: hue-rotate(Xdeg)
+    HueRotate(i32),
+    /// backdrop-filter: invert(X%)
+    Invert(i32),
+    /// backdrop-filter: saturate(X / 100)
+    Saturate(i32),
+    /// backdrop-filter: sepia(X%)
+    Sepia(i32),
+    /// backdrop-filter: drop-shadow(value)
+    DropShadow(DropShadow),
 }
 
 impl From<BackdropFilter> for Style {
@@ -15,27 +31,41 @@ impl From<BackdropFilter> for Style {
 }
 
 impl BackdropFilter {
-    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
-        match self {
-            BackdropFilter::Blur(x) => write!(stream, "bf-blur-{}", x)?,
-        };
+    /// Returns the filter function applied to the backdrop.
+    pub fn filter(&self) -> Filter {
+        match *self {
+            BackdropFilter::Blur(x) => Filter::Blur(x),
+            BackdropFilter::Brightness(x) => Filter::Brightness(x),
+            BackdropFilter::Contrast(x) => Filter::Contrast(x),
+            BackdropFilter::Grayscale(x) => Filter::Grayscale(x),
+            BackdropFilter::HueRotate(x) => Filter::HueRotate(x),
+            BackdropFilter::Invert(x) => Filter::Invert(x),
+            BackdropFilter::Saturate(x) => Filter::Saturate(x),
+            BackdropFilter::Sepia(x) => Filter::Sepia(x),
+            BackdropFilter::DropShadow(x) => Filter::DropShadow(x),
+        }
+    }
 
-        Ok(())
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        write!(stream, "bf-")?;
+        self.filter().write_classname(stream)
     }
 
     pub(crate) fn write_css_statement<T>(
         &self,
         stream: &mut String,
-        _options: &T,
+        options: &T,
     ) -> Result<(), StyleError>
     where
         T: crate::StyleOptions,
     {
-        match self {
-            BackdropFilter::Blur(x) => write!(stream, "backdrop-filter:blur({}px)", x)?,
-        };
-
-        Ok(())
+        // Safari before 18 only supports the prefixed property
+        self.filter().write_composed(
+            stream,
+            options,
+            "backdrop-",
+            &["-webkit-backdrop-filter", "backdrop-filter"],
+        )
     }
 }
 
@@ -52,4 +82,44 @@ pub trait BackdropFilterTrait: Styleable {
     fn backdrop_filter_blur(self, value: impl Into<i32>) -> Self::Output {
         self.style(BackdropFilter::Blur(value.into()))
     }
+
+    #[inline]
+    fn backdrop_filter_brightness(self, value: impl Into<i32>) -> Self::Output {
+        self.style(BackdropFilter::Brightness(value.into()))
+    }
+
+    #[inline]
+    fn backdrop_filter_contrast(self, value: impl Into<i32>) -> Self::Output {
+        self.style(BackdropFilter::Contrast(value.into()))
+    }
+
+    #[inline]
+    fn backdrop_filter_grayscale(self, value: impl Into<i32>) -> Self::Output {
+        self.style(BackdropFilter::Grayscale(value.into()))
+    }
+
+    #[inline]
+    fn backdrop_filter_hue_rotate(self, value: impl Into<i32>) -> Self::Output {
+        self.style(BackdropFilter::HueRotate(value.into()))
+    }
+
+    #[inline]
+    fn backdrop_filter_invert(self, value: impl Into<i32>) -> Self::Output {
+        self.style(BackdropFilter::Invert(value.into()))
+    }
+
+    #[inline]
+    fn backdrop_filter_saturate(self, value: impl Into<i32>) -> Self::Output {
+        self.style(BackdropFilter::Saturate(value.into()))
+    }
+
+    #[inline]
+    fn backdrop_filter_sepia(self, value: impl Into<i32>) -> Self::Output {
+        self.style(BackdropFilter::Sepia(value.into()))
+    }
+
+    #[inline]
+    fn backdrop_filter_drop_shadow(self, value: impl Into<DropShadow>) -> Self::Output {
+        self.style(BackdropFilter::DropShadow(value.into()))
+    }
 }
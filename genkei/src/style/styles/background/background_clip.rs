@@ -0,0 +1,55 @@
+use crate::{Background, Style, StyleError};
+use std::fmt::Write;
+
+/// Represents the background-clip style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BackgroundClip {
+    /// background-clip: border-box
+    Border,
+    /// background-clip: padding-box
+    Padding,
+    /// background-clip: content-box
+    Content,
+    /// background-clip: text
+    Text,
+}
+
+impl From<BackgroundClip> for Style {
+    fn from(value: BackgroundClip) -> Self {
+        Background::Clip(value).into()
+    }
+}
+
+impl BackgroundClip {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            BackgroundClip::Border => write!(stream, "bg-clip-border")?,
+            BackgroundClip::Padding => write!(stream, "bg-clip-padding")?,
+            BackgroundClip::Content => write!(stream, "bg-clip-content")?,
+            BackgroundClip::Text => write!(stream, "bg-clip-text")?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            BackgroundClip::Border => write!(stream, "background-clip:border-box")?,
+            BackgroundClip::Padding => write!(stream, "background-clip:padding-box")?,
+            BackgroundClip::Content => write!(stream, "background-clip:content-box")?,
+            // safari still requires the prefixed property for text clipping
+            BackgroundClip::Text => {
+                write!(stream, "-webkit-background-clip:text;background-clip:text")?
+            }
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{Background, Style, StyleError};
+use std::fmt::Write;
+
+/// Represents the direction of a linear gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GradientDirection {
+    /// linear-gradient(to top, ...)
+    ToTop,
+    /// linear-gradient(to top right, ...)
+    ToTopRight,
+    /// linear-gradient(to right, ...)
+    ToRight,
+    /// linear-gradient(to bottom right, ...)
+    ToBottomRight,
+    /// linear-gradient(to bottom, ...)
+    ToBottom,
+    /// linear-gradient(to bottom left, ...)
+    ToBottomLeft,
+    /// linear-gradient(to left, ...)
+    ToLeft,
+    /// linear-gradient(to top left, ...)
+    ToTopLeft,
+}
+
+impl From<GradientDirection> for Style {
+    fn from(value: GradientDirection) -> Self {
+        Background::Gradient(value).into()
+    }
+}
+
+impl GradientDirection {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            GradientDirection::ToTop => write!(stream, "bg-gradient-to-t")?,
+            GradientDirection::ToTopRight => write!(stream, "bg-gradient-to-tr")?,
+            GradientDirection::ToRight => write!(stream, "bg-gradient-to-r")?,
+            GradientDirection::ToBottomRight => write!(stream, "bg-gradient-to-br")?,
+            GradientDirection::ToBottom => write!(stream, "bg-gradient-to-b")?,
+            GradientDirection::ToBottomLeft => write!(stream, "bg-gradient-to-bl")?,
+            GradientDirection::ToLeft => write!(stream, "bg-gradient-to-l")?,
+            GradientDirection::ToTopLeft => write!(stream, "bg-gradient-to-tl")?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        let direction = match self {
+            GradientDirection::ToTop => "to top",
+            GradientDirection::ToTopRight => "to top right",
+            GradientDirection::ToRight => "to right",
+            GradientDirection::ToBottomRight => "to bottom right",
+            GradientDirection::ToBottom => "to bottom",
+            GradientDirection::ToBottomLeft => "to bottom left",
+            GradientDirection::ToLeft => "to left",
+            GradientDirection::ToTopLeft => "to top left",
+        };
+        write!(
+            stream,
+            "background-image:linear-gradient({},var(--tw-gradient-stops))",
+            direction
+        )?;
+
+        Ok(())
+    }
+}
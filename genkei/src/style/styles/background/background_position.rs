@@ -0,0 +1,72 @@
+use crate::{Background, Style, StyleError};
+use std::fmt::Write;
+
+/// Represents the background-position style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BackgroundPosition {
+    /// background-position: bottom
+    Bottom,
+    /// background-position: center
+    Center,
+    /// background-position: left
+    Left,
+    /// background-position: left bottom
+    LeftBottom,
+    /// background-position: left top
+    LeftTop,
+    /// background-position: right
+    Right,
+    /// background-position: right bottom
+    RightBottom,
+    /// background-position: right top
+    RightTop,
+    /// background-position: top
+    Top,
+}
+
+impl From<BackgroundPosition> for Style {
+    fn from(value: BackgroundPosition) -> Self {
+        Background::Position(value).into()
+    }
+}
+
+impl BackgroundPosition {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            BackgroundPosition::Bottom => write!(stream, "bg-bottom")?,
+            BackgroundPosition::Center => write!(stream, "bg-center")?,
+            BackgroundPosition::Left => write!(stream, "bg-left")?,
+            BackgroundPosition::LeftBottom => write!(stream, "bg-left-bottom")?,
+            BackgroundPosition::LeftTop => write!(stream, "bg-left-top")?,
+            BackgroundPosition::Right => write!(stream, "bg-right")?,
+            BackgroundPosition::RightBottom => write!(stream, "bg-right-bottom")?,
+            BackgroundPosition::RightTop => write!(stream, "bg-right-top")?,
+            BackgroundPosition::Top => write!(stream, "bg-top")?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            BackgroundPosition::Bottom => write!(stream, "background-position:bottom")?,
+            BackgroundPosition::Center => write!(stream, "background-position:center")?,
+            BackgroundPosition::Left => write!(stream, "background-position:left")?,
+            BackgroundPosition::LeftBottom => write!(stream, "background-position:left bottom")?,
+            BackgroundPosition::LeftTop => write!(stream, "background-position:left top")?,
+            BackgroundPosition::Right => write!(stream, "background-position:right")?,
+            BackgroundPosition::RightBottom => write!(stream, "background-position:right bottom")?,
+            BackgroundPosition::RightTop => write!(stream, "background-position:right top")?,
+            BackgroundPosition::Top => write!(stream, "background-position:top")?,
+        };
+
+        Ok(())
+    }
+}
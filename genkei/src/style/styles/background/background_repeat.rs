@@ -0,0 +1,60 @@
+use crate::{Background, Style, StyleError};
+use std::fmt::Write;
+
+/// Represents the background-repeat style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BackgroundRepeat {
+    /// background-repeat: repeat
+    Repeat,
+    /// background-repeat: no-repeat
+    NoRepeat,
+    /// background-repeat: repeat-x
+    RepeatX,
+    /// background-repeat: repeat-y
+    RepeatY,
+    /// background-repeat: round
+    Round,
+    /// background-repeat: space
+    Space,
+}
+
+impl From<BackgroundRepeat> for Style {
+    fn from(value: BackgroundRepeat) -> Self {
+        Background::Repeat(value).into()
+    }
+}
+
+impl BackgroundRepeat {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            BackgroundRepeat::Repeat => write!(stream, "bg-repeat")?,
+            BackgroundRepeat::NoRepeat => write!(stream, "bg-no-repeat")?,
+            BackgroundRepeat::RepeatX => write!(stream, "bg-repeat-x")?,
+            BackgroundRepeat::RepeatY => write!(stream, "bg-repeat-y")?,
+            BackgroundRepeat::Round => write!(stream, "bg-repeat-round")?,
+            BackgroundRepeat::Space => write!(stream, "bg-repeat-space")?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            BackgroundRepeat::Repeat => write!(stream, "background-repeat:repeat")?,
+            BackgroundRepeat::NoRepeat => write!(stream, "background-repeat:no-repeat")?,
+            BackgroundRepeat::RepeatX => write!(stream, "background-repeat:repeat-x")?,
+            BackgroundRepeat::RepeatY => write!(stream, "background-repeat:repeat-y")?,
+            BackgroundRepeat::Round => write!(stream, "background-repeat:round")?,
+            BackgroundRepeat::Space => write!(stream, "background-repeat:space")?,
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{Background, Style, StyleError};
+use std::fmt::Write;
+
+/// Represents the background-size style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BackgroundSize {
+    /// background-size: auto
+    Auto,
+    /// background-size: cover
+    Cover,
+    /// background-size: contain
+    Contain,
+}
+
+impl From<BackgroundSize> for Style {
+    fn from(value: BackgroundSize) -> Self {
+        Background::Size(value).into()
+    }
+}
+
+impl BackgroundSize {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            BackgroundSize::Auto => write!(stream, "bg-auto")?,
+            BackgroundSize::Cover => write!(stream, "bg-cover")?,
+            BackgroundSize::Contain => write!(stream, "bg-contain")?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            BackgroundSize::Auto => write!(stream, "background-size:auto")?,
+            BackgroundSize::Cover => write!(stream, "background-size:cover")?,
+            BackgroundSize::Contain => write!(stream, "background-size:contain")?,
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+mod background_clip;
+mod background_gradient;
+mod background_position;
+mod background_repeat;
+mod background_size;
+
+pub use background_clip::*;
+pub use background_gradient::*;
+pub use background_position::*;
+pub use background_repeat::*;
+pub use background_size::*;
+
+use crate::{AnyColor, ColorValue, Str, Style, StyleError, Styleable};
+use std::fmt::Write;
+
+/// Represents the background styles, except for `background-color` which is handled by
+/// [`ColorStyle`](crate::ColorStyle).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Background {
+    /// background-image: none;
+    None,
+    /// background-image: linear-gradient(direction, var(--tw-gradient-stops));
+    Gradient(GradientDirection),
+    /// background-image: url(value);
+    Image(Str),
+    /// The starting color stop of a gradient.
+    From(AnyColor),
+    /// The middle color stop of a gradient.
+    Via(AnyColor),
+    /// The ending color stop of a gradient.
+    To(AnyColor),
+    /// background-size: value;
+    Size(BackgroundSize),
+    /// background-position: value;
+    Position(BackgroundPosition),
+    /// background-repeat: value;
+    Repeat(BackgroundRepeat),
+    /// background-clip: value;
+    Clip(BackgroundClip),
+}
+
+impl From<Background> for Style {
+    fn from(value: Background) -> Self {
+        Style::Background(value)
+    }
+}
+
+impl Background {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            Background::None => write!(stream, "bg-none")?,
+            Background::Gradient(x) => x.write_classname(stream)?,
+            Background::Image(x) => {
+                write!(stream, "bg-[url(")?;
+                crate::style::write_arbitrary_classname(stream, x)?;
+                write!(stream, ")]")?;
+            }
+            Background::From(x) => {
+                write!(stream, "from-")?;
+                x.write_color_name(stream)?;
+            }
+            Background::Via(x) => {
+                write!(stream, "via-")?;
+                x.write_color_name(stream)?;
+            }
+            Background::To(x) => {
+                write!(stream, "to-")?;
+                x.write_color_name(stream)?;
+            }
+            Background::Size(x) => x.write_classname(stream)?,
+            Background::Position(x) => x.write_classname(stream)?,
+            Background::Repeat(x) => x.write_classname(stream)?,
+            Background::Clip(x) => x.write_classname(stream)?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            Background::None => write!(stream, "background-image:none")?,
+            Background::Gradient(x) => x.write_css_statement(stream, options)?,
+            Background::Image(x) => {
+                write!(stream, "background-image:url(")?;
+                crate::style::write_css_string(stream, x)?;
+                write!(stream, ")")?;
+            }
+            // `from` and `via` fade to a transparent version of their color, so a gradient
+            // without an explicit `to` stop still renders.
+            Background::From(x) => {
+                write!(stream, "--tw-gradient-from:")?;
+                x.write_color_value(stream, options)?;
+                write!(stream, ";--tw-gradient-to:")?;
+                x.transparent().write_color_value(stream, options)?;
+                write!(
+                    stream,
+                    ";--tw-gradient-stops:var(--tw-gradient-from),var(--tw-gradient-to)"
+                )?;
+            }
+            Background::Via(x) => {
+                write!(stream, "--tw-gradient-to:")?;
+                x.transparent().write_color_value(stream, options)?;
+                write!(stream, ";--tw-gradient-stops:var(--tw-gradient-from),")?;
+                x.write_color_value(stream, options)?;
+                write!(stream, ",var(--tw-gradient-to)")?;
+            }
+            Background::To(x) => {
+                write!(stream, "--tw-gradient-to:")?;
+                x.write_color_value(stream, options)?;
+            }
+            Background::Size(x) => x.write_css_statement(stream, options)?,
+            Background::Position(x) => x.write_css_statement(stream, options)?,
+            Background::Repeat(x) => x.write_css_statement(stream, options)?,
+            Background::Clip(x) => x.write_css_statement(stream, options)?,
+        };
+
+        Ok(())
+    }
+}
+
+impl<T> BackgroundTrait for T where T: Styleable {}
+
+/// A trait for the background style attributes.
+pub trait BackgroundTrait: Styleable {
+    #[inline]
+    fn bg_none(self) -> Self::Output {
+        self.style(Background::None)
+    }
+
+    #[inline]
+    fn bg_gradient(self, value: impl Into<GradientDirection>) -> Self::Output {
+        self.style(Background::Gradient(value.into()))
+    }
+
+    #[inline]
+    fn gradient_from(self, value: impl Into<AnyColor>) -> Self::Output {
+        self.style(Background::From(value.into()))
+    }
+
+    #[inline]
+    fn gradient_via(self, value: impl Into<AnyColor>) -> Self::Output {
+        self.style(Background::Via(value.into()))
+    }
+
+    #[inline]
+    fn gradient_to(self, value: impl Into<AnyColor>) -> Self::Output {
+        self.style(Background::To(value.into()))
+    }
+
+    #[inline]
+    fn bg_image(self, url: impl Into<Str>) -> Self::Output {
+        self.style(Background::Image(url.into()))
+    }
+
+    #[inline]
+    fn bg_size(self, value: impl Into<BackgroundSize>) -> Self::Output {
+        self.style(Background::Size(value.into()))
+    }
+
+    #[inline]
+    fn bg_cover(self) -> Self::Output {
+        self.bg_size(BackgroundSize::Cover)
+    }
+
+    #[inline]
+    fn bg_contain(self) -> Self::Output {
+        self.bg_size(BackgroundSize::Contain)
+    }
+
+    #[inline]
+    fn bg_position(self, value: impl Into<BackgroundPosition>) -> Self::Output {
+        self.style(Background::Position(value.into()))
+    }
+
+    #[inline]
+    fn bg_center(self) -> Self::Output {
+        self.bg_position(BackgroundPosition::Center)
+    }
+
+    #[inline]
+    fn bg_repeat(self, value: impl Into<BackgroundRepeat>) -> Self::Output {
+        self.style(Background::Repeat(value.into()))
+    }
+
+    #[inline]
+    fn bg_no_repeat(self) -> Self::Output {
+        self.bg_repeat(BackgroundRepeat::NoRepeat)
+    }
+
+    #[inline]
+    fn bg_clip(self, value: impl Into<BackgroundClip>) -> Self::Output {
+        self.style(Background::Clip(value.into()))
+    }
+
+    #[inline]
+    fn bg_clip_text(self) -> Self::Output {
+        self.bg_clip(BackgroundClip::Text)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Color, Style, StyleError, Styleable};
+use crate::{AnyColor, ColorValue, Style, StyleError, Styleable};
 use std::fmt::Write;
 
 /// Represents the border-width styles.
@@ -96,7 +96,7 @@ pub enum Border {
     /// border-width: value;
     Width(BorderWidth),
     /// border-color: value;
-    Color(Color),
+    Color(AnyColor),
     /// border-radius: value;
     Radius(i32),
 }
@@ -133,11 +133,11 @@ impl Border {
             Border::Width(x) => x.write_css_statement(stream, options)?,
             Border::Color(x) => {
                 write!(stream, "border-color:")?;
-                x.write_css_value(stream, options)?;
+                x.write_color_value(stream, options)?;
             }
             Border::Radius(x) => {
                 write!(stream, "border-radius:")?;
-                options.spacing(stream, *x)?;
+                options.radius(stream, *x)?;
             }
         };
 
@@ -185,7 +185,7 @@ pub trait BorderTrait: Styleable {
     }
 
     #[inline]
-    fn border_color(self, value: impl Into<Color>) -> Self::Output {
+    fn border_color(self, value: impl Into<AnyColor>) -> Self::Output {
         self.style(Border::Color(value.into()))
     }
 
@@ -1,4 +1,4 @@
-use crate::{Color, ColorValue, ComplexColor, Style, StyleError, Styleable};
+use crate::{AnyColor, Color, ColorValue, ComplexColor, Style, StyleError, Styleable};
 use std::fmt::Write;
 
 /// Represents the `color` and `background-color` style attributes.
@@ -22,6 +22,17 @@ impl From<ColorStyle<ComplexColor>> for Style {
     }
 }
 
+impl From<ColorStyle<AnyColor>> for Style {
+    fn from(value: ColorStyle<AnyColor>) -> Self {
+        match value {
+            ColorStyle::Foreground(AnyColor::Simple(x)) => ColorStyle::Foreground(x).into(),
+            ColorStyle::Foreground(AnyColor::Complex(x)) => ColorStyle::Foreground(x).into(),
+            ColorStyle::Background(AnyColor::Simple(x)) => ColorStyle::Background(x).into(),
+            ColorStyle::Background(AnyColor::Complex(x)) => ColorStyle::Background(x).into(),
+        }
+    }
+}
+
 impl<V> ColorStyle<V>
 where
     V: ColorValue,
@@ -69,17 +80,17 @@ impl<T> ColorTrait for T where T: Styleable {}
 /// A trait for the color style attribute.
 pub trait ColorTrait: Styleable {
     #[inline]
-    fn bg_color(self, value: impl Into<Color>) -> Self::Output {
+    fn bg_color(self, value: impl Into<AnyColor>) -> Self::Output {
         self.style(ColorStyle::Background(value.into()))
     }
 
     #[inline]
-    fn fg_color(self, value: impl Into<Color>) -> Self::Output {
+    fn fg_color(self, value: impl Into<AnyColor>) -> Self::Output {
         self.style(ColorStyle::Foreground(value.into()))
     }
 
     #[inline]
-    fn color(self, value: impl Into<Color>) -> Self::Output {
+    fn color(self, value: impl Into<AnyColor>) -> Self::Output {
         self.fg_color(value)
     }
 }
@@ -0,0 +1,116 @@
+use crate::{ContainerSize, Str, Style, StyleError, Styleable};
+use std::fmt::Write;
+
+/// Marks an element as a query container, optionally named.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Container {
+    pub name: Option<Str>,
+}
+
+impl From<Container> for Style {
+    fn from(value: Container) -> Self {
+        Style::Container(value)
+    }
+}
+
+/// Returns the name back, failing unless it's a valid `container-name`.
+fn checked_name(name: &str) -> Result<&str, StyleError> {
+    if !crate::style::is_css_identifier(name) || matches!(name, "none" | "and" | "not" | "or") {
+        return Err(StyleError::InvalidName(
+            name.to_string(),
+            "a container name must be a css identifier other than `none`, `and`, `not` and `or`",
+        ));
+    }
+
+    Ok(name)
+}
+
+impl Container {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        write!(stream, "@container")?;
+        if let Some(name) = &self.name {
+            write!(stream, "/{}", checked_name(name)?)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        write!(stream, "container-type:inline-size")?;
+        if let Some(name) = &self.name {
+            write!(stream, ";container-name:{}", checked_name(name)?)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Container queries, the styles apply from the given width of the closest container.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ContainerQuery {
+    /// Queries the closest container, e.g. `@md`.
+    Size(ContainerSize),
+    /// Queries the closest container with the given name, e.g. `@md/sidebar`.
+    Named(Str, ContainerSize),
+}
+
+impl From<ContainerSize> for ContainerQuery {
+    fn from(value: ContainerSize) -> Self {
+        ContainerQuery::Size(value)
+    }
+}
+
+impl ContainerQuery {
+    /// Returns the container size this query applies from.
+    pub fn size(&self) -> ContainerSize {
+        match self {
+            ContainerQuery::Size(x) | ContainerQuery::Named(_, x) => *x,
+        }
+    }
+
+    pub(crate) fn write_selector(&self, stream: &mut String) -> Result<(), StyleError> {
+        write!(stream, "@container")?;
+        if let ContainerQuery::Named(name, _) = self {
+            write!(stream, " {}", checked_name(name)?)?;
+        }
+        write!(stream, " (min-width:{}rem)", self.size().rem())?;
+
+        Ok(())
+    }
+
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        stream.push('@');
+        self.size().write_classname(stream)?;
+        if let ContainerQuery::Named(name, _) = self {
+            write!(stream, "/{}", checked_name(name)?)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> ContainerTrait for T where T: Styleable {}
+
+/// A trait for the container style attributes.
+pub trait ContainerTrait: Styleable {
+    /// container-type: inline-size;
+    #[inline]
+    fn container(self) -> Self::Output {
+        self.style(Container { name: None })
+    }
+
+    /// container-type: inline-size; container-name: name;
+    #[inline]
+    fn container_named(self, name: impl Into<Str>) -> Self::Output {
+        self.style(Container {
+            name: Some(name.into()),
+        })
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{Str, Style, StyleError, Styleable};
+use std::fmt::Write;
+
+/// Represents the generated content of the `::before` and `::after` pseudo-elements.
+///
+/// The value is stored in `--tw-content`, the `before:` and `after:` variants always emit
+/// `content:var(--tw-content,'')`, so the pseudo-elements render without an explicit content
+/// style and without `reset.css`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Content {
+    /// content: none;
+    None,
+    /// content: "value";
+    Text(Str),
+}
+
+impl From<Content> for Style {
+    fn from(value: Content) -> Self {
+        Style::Content(value)
+    }
+}
+
+impl Content {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            Content::None => write!(stream, "content-none")?,
+            Content::Text(x) => {
+                write!(stream, "content-['")?;
+                crate::style::write_arbitrary_classname(stream, x)?;
+                write!(stream, "']")?;
+            }
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            Content::None => write!(stream, "--tw-content:none")?,
+            Content::Text(x) => {
+                write!(stream, "--tw-content:")?;
+                crate::style::write_css_string(stream, x)?;
+            }
+        };
+        write!(stream, ";content:var(--tw-content,'')")?;
+
+        Ok(())
+    }
+}
+
+impl<T> ContentTrait for T where T: Styleable {}
+
+/// A trait for the content style attributes.
+pub trait ContentTrait: Styleable {
+    /// Sets the generated content of a `before:` or `after:` pseudo-element.
+    #[inline]
+    fn content(self, value: impl Into<Str>) -> Self::Output {
+        self.style(Content::Text(value.into()))
+    }
+
+    #[inline]
+    fn content_none(self) -> Self::Output {
+        self.style(Content::None)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{Filter, Style, StyleError};
+use std::fmt::Write;
+
+/// Represents the drop-shadow filter scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DropShadow {
+    /// drop-shadow(0 1px 1px rgba(0,0,0,0.05))
+    Sm,
+    /// drop-shadow(0 1px 2px rgba(0,0,0,0.1)) drop-shadow(0 1px 1px rgba(0,0,0,0.06))
+    Base,
+    /// drop-shadow(0 4px 3px rgba(0,0,0,0.07)) drop-shadow(0 2px 2px rgba(0,0,0,0.06))
+    Md,
+    /// drop-shadow(0 10px 8px rgba(0,0,0,0.04)) drop-shadow(0 4px 3px rgba(0,0,0,0.1))
+    Lg,
+    /// drop-shadow(0 20px 13px rgba(0,0,0,0.03)) drop-shadow(0 8px 5px rgba(0,0,0,0.08))
+    Xl,
+    /// drop-shadow(0 25px 25px rgba(0,0,0,0.15))
+    Xxl,
+    /// drop-shadow(0 0 rgba(0,0,0,0))
+    None,
+}
+
+impl From<DropShadow> for Style {
+    fn from(value: DropShadow) -> Self {
+        Filter::DropShadow(value).into()
+    }
+}
+
+impl DropShadow {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            DropShadow::Sm => write!(stream, "sm")?,
+            DropShadow::Base => write!(stream, "base")?,
+            DropShadow::Md => write!(stream, "md")?,
+            DropShadow::Lg => write!(stream, "lg")?,
+            DropShadow::Xl => write!(stream, "xl")?,
+            DropShadow::Xxl => write!(stream, "2xl")?,
+            DropShadow::None => write!(stream, "none")?,
+        };
+
+        Ok(())
+    }
+
+    /// Returns the default `drop-shadow(..)` functions, see
+    /// [`StyleOptions::drop_shadow`](crate::StyleOptions::drop_shadow).
+    pub fn to_css_value(&self) -> &'static str {
+        match self {
+            DropShadow::Sm => "drop-shadow(0 1px 1px rgba(0,0,0,0.05))",
+            DropShadow::Base => {
+                "drop-shadow(0 1px 2px rgba(0,0,0,0.1)) drop-shadow(0 1px 1px rgba(0,0,0,0.06))"
+            }
+            DropShadow::Md => {
+                "drop-shadow(0 4px 3px rgba(0,0,0,0.07)) drop-shadow(0 2px 2px rgba(0,0,0,0.06))"
+            }
+            DropShadow::Lg => {
+                "drop-shadow(0 10px 8px rgba(0,0,0,0.04)) drop-shadow(0 4px 3px rgba(0,0,0,0.1))"
+            }
+            DropShadow::Xl => {
+                "drop-shadow(0 20px 13px rgba(0,0,0,0.03)) drop-shadow(0 8px 5px rgba(0,0,0,0.08))"
+            }
+            DropShadow::Xxl => "drop-shadow(0 25px 25px rgba(0,0,0,0.15))",
+            DropShadow::None => "drop-shadow(0 0 rgba(0,0,0,0))",
+        }
+    }
+}
@@ -0,0 +1,204 @@
+mod drop_shadow;
+
+pub use drop_shadow::*;
+
+use crate::{Style, StyleError, Styleable};
+use std::fmt::Write;
+
+/// The filter functions in the order they are applied. Every utility only sets its own
+/// `--tw-*` variable, so several filters on the same element compose instead of overwriting
+/// each other. The variables are reset for every element in `reset.css`, the empty fallbacks
+/// cover stylesheets rendered without the reset. Only the function names are used.
+const FUNCTIONS: [Filter; 9] = [
+    Filter::Blur(0),
+    Filter::Brightness(0),
+    Filter::Contrast(0),
+    Filter::Grayscale(0),
+    Filter::HueRotate(0),
+    Filter::Invert(0),
+    Filter::Saturate(0),
+    Filter::Sepia(0),
+    Filter::DropShadow(DropShadow::None),
+];
+
+/// Represents the filter style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Filter {
+    /// blur(Xpx)
+    Blur(i32),
+    /// brightness(X / 100)
+    Brightness(i32),
+    /// contrast(X / 100)
+    Contrast(i32),
+    /// grayscale(X%)
+    Grayscale(i32),
+    /// hue-rotate(Xdeg)
+    HueRotate(i32),
+    /// invert(X%)
+    Invert(i32),
+    /// saturate(X / 100)
+    Saturate(i32),
+    /// sepia(X%)
+    Sepia(i32),
+    /// drop-shadow(value)
+    DropShadow(DropShadow),
+}
+
+impl From<Filter> for Style {
+    fn from(value: Filter) -> Self {
+        Style::Filter(value)
+    }
+}
+
+impl Filter {
+    fn function_name(&self) -> &'static str {
+        match self {
+            Filter::Blur(_) => "blur",
+            Filter::Brightness(_) => "brightness",
+            Filter::Contrast(_) => "contrast",
+            Filter::Grayscale(_) => "grayscale",
+            Filter::HueRotate(_) => "hue-rotate",
+            Filter::Invert(_) => "invert",
+            Filter::Saturate(_) => "saturate",
+            Filter::Sepia(_) => "sepia",
+            Filter::DropShadow(_) => "drop-shadow",
+        }
+    }
+
+    fn write_function<T>(&self, stream: &mut String, options: &T) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            Filter::Blur(x) => write!(stream, "blur({}px)", x)?,
+            Filter::Brightness(x) => write!(stream, "brightness({})", *x as f32 / 100.0)?,
+            Filter::Contrast(x) => write!(stream, "contrast({})", *x as f32 / 100.0)?,
+            Filter::Grayscale(x) => write!(stream, "grayscale({}%)", x)?,
+            Filter::HueRotate(x) => write!(stream, "hue-rotate({}deg)", x)?,
+            Filter::Invert(x) => write!(stream, "invert({}%)", x)?,
+            Filter::Saturate(x) => write!(stream, "saturate({})", *x as f32 / 100.0)?,
+            Filter::Sepia(x) => write!(stream, "sepia({}%)", x)?,
+            Filter::DropShadow(x) => options.drop_shadow(stream, x)?,
+        };
+
+        Ok(())
+    }
+
+    /// Writes `--tw-{prefix}{function}:value;{property}:var(..) var(..) ...` with the same
+    /// value for every property, e.g. a vendor prefixed one.
+    pub(crate) fn write_composed<T>(
+        &self,
+        stream: &mut String,
+        options: &T,
+        prefix: &str,
+        properties: &[&str],
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        write!(stream, "--tw-{}{}:", prefix, self.function_name())?;
+        self.write_function(stream, options)?;
+        for property in properties {
+            write!(stream, ";{}:", property)?;
+            for (i, function) in FUNCTIONS.iter().enumerate() {
+                if i > 0 {
+                    stream.push(' ');
+                }
+                write!(stream, "var(--tw-{}{},)", prefix, function.function_name())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        write!(stream, "{}-", self.function_name())?;
+        match self {
+            Filter::Blur(x)
+            | Filter::Brightness(x)
+            | Filter::Contrast(x)
+            | Filter::Grayscale(x)
+            | Filter::HueRotate(x)
+            | Filter::Invert(x)
+            | Filter::Saturate(x)
+            | Filter::Sepia(x) => write!(stream, "{}", x)?,
+            Filter::DropShadow(x) => x.write_classname(stream)?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        self.write_composed(stream, options, "", &["filter"])
+    }
+}
+
+impl<T> FilterTrait for T where T: Styleable {}
+
+/// A trait for the filter style attributes.
+pub trait FilterTrait: Styleable {
+    #[inline]
+    fn filter(self, value: impl Into<Filter>) -> Self::Output {
+        self.style(value.into())
+    }
+
+    /// filter: blur(Xpx);
+    #[inline]
+    fn blur(self, value: impl Into<i32>) -> Self::Output {
+        self.filter(Filter::Blur(value.into()))
+    }
+
+    /// filter: brightness(X / 100);
+    #[inline]
+    fn brightness(self, value: impl Into<i32>) -> Self::Output {
+        self.filter(Filter::Brightness(value.into()))
+    }
+
+    /// filter: contrast(X / 100);
+    #[inline]
+    fn contrast(self, value: impl Into<i32>) -> Self::Output {
+        self.filter(Filter::Contrast(value.into()))
+    }
+
+    /// filter: grayscale(X%);
+    #[inline]
+    fn grayscale(self, value: impl Into<i32>) -> Self::Output {
+        self.filter(Filter::Grayscale(value.into()))
+    }
+
+    /// filter: hue-rotate(Xdeg);
+    #[inline]
+    fn hue_rotate(self, value: impl Into<i32>) -> Self::Output {
+        self.filter(Filter::HueRotate(value.into()))
+    }
+
+    /// filter: invert(X%);
+    #[inline]
+    fn invert(self, value: impl Into<i32>) -> Self::Output {
+        self.filter(Filter::Invert(value.into()))
+    }
+
+    /// filter: saturate(X / 100);
+    #[inline]
+    fn saturate(self, value: impl Into<i32>) -> Self::Output {
+        self.filter(Filter::Saturate(value.into()))
+    }
+
+    /// filter: sepia(X%);
+    #[inline]
+    fn sepia(self, value: impl Into<i32>) -> Self::Output {
+        self.filter(Filter::Sepia(value.into()))
+    }
+
+    #[inline]
+    fn drop_shadow(self, value: impl Into<DropShadow>) -> Self::Output {
+        self.filter(Filter::DropShadow(value.into()))
+    }
+}
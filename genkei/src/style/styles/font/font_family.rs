@@ -32,15 +32,17 @@ impl FontFamily {
     pub(crate) fn write_css_statement<T>(
         &self,
         stream: &mut String,
-        _options: &T,
+        options: &T,
     ) -> Result<(), StyleError>
     where
         T: crate::StyleOptions,
     {
-        write!(stream, "font-family:{}", self.to_css_value())?;
-        Ok(())
+        write!(stream, "font-family:")?;
+        options.font_family(stream, self)
     }
 
+    /// Returns the default font stack, see
+    /// [`StyleOptions::font_family`](crate::StyleOptions::font_family).
     pub fn to_css_value(&self) -> &'static str {
         match self {
             FontFamily::Sans => {
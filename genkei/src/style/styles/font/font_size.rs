@@ -52,25 +52,43 @@ impl FontSize {
         Ok(())
     }
 
+    /// Returns the default font size, see
+    /// [`StyleOptions::font_size_scale`](crate::StyleOptions::font_size_scale).
+    pub fn font_size(&self) -> &'static str {
+        match self {
+            FontSize::Xs => "0.75rem",
+            FontSize::Sm => "0.875rem",
+            FontSize::Base => "1rem",
+            FontSize::Lg => "1.125rem",
+            FontSize::Xl => "1.25rem",
+            FontSize::Xxl => "1.5rem",
+            FontSize::Xxxl => "1.875rem",
+            FontSize::Xxxxl => "2.25rem",
+        }
+    }
+
+    /// Returns the default line height, see
+    /// [`StyleOptions::font_size_scale`](crate::StyleOptions::font_size_scale).
+    pub fn line_height(&self) -> &'static str {
+        match self {
+            FontSize::Xs => "1rem",
+            FontSize::Sm => "1.25rem",
+            FontSize::Base => "1.5rem",
+            FontSize::Lg | FontSize::Xl => "1.75rem",
+            FontSize::Xxl => "2rem",
+            FontSize::Xxxl => "2.25rem",
+            FontSize::Xxxxl => "2.5rem",
+        }
+    }
+
     pub(crate) fn write_css_statement<T>(
         &self,
         stream: &mut String,
-        _options: &T,
+        options: &T,
     ) -> Result<(), StyleError>
     where
         T: crate::StyleOptions,
     {
-        match self {
-            FontSize::Xs => write!(stream, "font-size:0.75rem;line-height:1rem")?,
-            FontSize::Sm => write!(stream, "font-size:0.875rem;line-height:1.25rem")?,
-            FontSize::Base => write!(stream, "font-size:1rem;line-height:1.5rem")?,
-            FontSize::Lg => write!(stream, "font-size:1.125rem;line-height:1.75rem")?,
-            FontSize::Xl => write!(stream, "font-size:1.25rem;line-height:1.75rem")?,
-            FontSize::Xxl => write!(stream, "font-size:1.5rem;line-height:2rem")?,
-            FontSize::Xxxl => write!(stream, "font-size:1.875rem;line-height:2.25rem")?,
-            FontSize::Xxxxl => write!(stream, "font-size:2.25rem;line-height:2.5rem")?,
-        };
-
-        Ok(())
+        options.font_size_scale(stream, self)
     }
 }
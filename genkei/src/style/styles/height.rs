@@ -126,6 +126,71 @@ impl MinHeight {
     }
 }
 
+/// Represents the max-height style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MaxHeight {
+    /// max-height: value;
+    Value(i32),
+    /// max-height: none;
+    None,
+    /// max-height: 100%;
+    Full,
+    /// max-height: 100vh;
+    Screen,
+    /// max-height: min-content;
+    MinContent,
+    /// max-height: max-content;
+    MaxContent,
+    /// max-height: fit-content;
+    FitContent,
+}
+
+impl From<MaxHeight> for Style {
+    fn from(value: MaxHeight) -> Self {
+        Style::MaxHeight(value)
+    }
+}
+
+impl MaxHeight {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            MaxHeight::Value(x) => write!(stream, "max-h-{}", x)?,
+            MaxHeight::None => write!(stream, "max-h-none")?,
+            MaxHeight::Full => write!(stream, "max-h-full")?,
+            MaxHeight::Screen => write!(stream, "max-h-screen")?,
+            MaxHeight::MinContent => write!(stream, "max-h-min")?,
+            MaxHeight::MaxContent => write!(stream, "max-h-max")?,
+            MaxHeight::FitContent => write!(stream, "max-h-fit")?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            MaxHeight::Value(x) => {
+                write!(stream, "max-height:")?;
+                options.spacing(stream, *x)?;
+            }
+            MaxHeight::None => write!(stream, "max-height:none")?,
+            MaxHeight::Full => write!(stream, "max-height:100%")?,
+            MaxHeight::Screen => write!(stream, "max-height:100vh")?,
+            MaxHeight::MinContent => write!(stream, "max-height:min-content")?,
+            MaxHeight::MaxContent => write!(stream, "max-height:max-content")?,
+            MaxHeight::FitContent => write!(stream, "max-height:fit-content")?,
+        };
+
+        Ok(())
+    }
+}
+
 impl<T> HeightTrait for T where T: Styleable {}
 
 /// Height style attributes.
@@ -189,4 +254,39 @@ pub trait HeightTrait: Styleable {
     fn min_h_fit_content(self) -> Self::Output {
         self.style(MinHeight::FitContent)
     }
+
+    #[inline]
+    fn max_h(self, value: impl Into<i32>) -> Self::Output {
+        self.style(MaxHeight::Value(value.into()))
+    }
+
+    #[inline]
+    fn max_h_none(self) -> Self::Output {
+        self.style(MaxHeight::None)
+    }
+
+    #[inline]
+    fn max_h_full(self) -> Self::Output {
+        self.style(MaxHeight::Full)
+    }
+
+    #[inline]
+    fn max_h_screen(self) -> Self::Output {
+        self.style(MaxHeight::Screen)
+    }
+
+    #[inline]
+    fn max_h_min_content(self) -> Self::Output {
+        self.style(MaxHeight::MinContent)
+    }
+
+    #[inline]
+    fn max_h_max_content(self) -> Self::Output {
+        self.style(MaxHeight::MaxContent)
+    }
+
+    #[inline]
+    fn max_h_fit_content(self) -> Self::Output {
+        self.style(MaxHeight::FitContent)
+    }
 }
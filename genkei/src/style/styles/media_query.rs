@@ -1,32 +1,124 @@
-use crate::StyleError;
+use crate::{Str, StyleError};
 use std::fmt::Write;
 
-/// Media queries for responsive design
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub enum MediaQuery {
-    /// Breakpoint: sm 640px
+/// Screen size breakpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Breakpoint {
+    /// sm: 640px
     Sm,
-    /// Breakpoint: md 768px
+    /// md: 768px
     Md,
-    /// Breakpoint: lg 1024px
+    /// lg: 1024px
     Lg,
-    /// Breakpoint: xl 1280px
+    /// xl: 1280px
     Xl,
-    /// Breakpoint: 2xl 1536px
+    /// 2xl: 1536px
     Xxl,
-    /// Breakpoint: 3xl 1920px
+    /// 3xl: 1920px
     Xxxl,
 }
 
+impl Breakpoint {
+    /// Returns the default screen width in pixels, see
+    /// [`StyleOptions::breakpoint`](crate::StyleOptions::breakpoint).
+    pub fn pixels(&self) -> i32 {
+        match self {
+            Breakpoint::Sm => 640,
+            Breakpoint::Md => 768,
+            Breakpoint::Lg => 1024,
+            Breakpoint::Xl => 1280,
+            Breakpoint::Xxl => 1536,
+            Breakpoint::Xxxl => 1920,
+        }
+    }
+
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            Breakpoint::Sm => write!(stream, "sm")?,
+            Breakpoint::Md => write!(stream, "md")?,
+            Breakpoint::Lg => write!(stream, "lg")?,
+            Breakpoint::Xl => write!(stream, "xl")?,
+            Breakpoint::Xxl => write!(stream, "xxl")?,
+            Breakpoint::Xxxl => write!(stream, "xxxl")?,
+        };
+
+        Ok(())
+    }
+}
+
+/// Media queries for responsive design
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MediaQuery {
+    /// From the breakpoint on, e.g. `md`.
+    Min(Breakpoint),
+    /// Below the breakpoint, e.g. `max-lg`. Nested in a min-width query, e.g. `md:max-lg`,
+    /// it restricts styles to a range.
+    Max(Breakpoint),
+    /// print
+    Print,
+    /// orientation: portrait
+    Portrait,
+    /// orientation: landscape
+    Landscape,
+    /// prefers-reduced-motion: no-preference
+    MotionSafe,
+    /// prefers-reduced-motion: reduce
+    MotionReduce,
+    /// prefers-contrast: more
+    ContrastMore,
+    /// An arbitrary media query, e.g. `MediaQuery::Raw("(min-width: 900px)".into())`. It's
+    /// written into the stylesheet as it is, so braces and `;` fail to render.
+    Raw(Str),
+}
+
+impl From<Breakpoint> for MediaQuery {
+    fn from(value: Breakpoint) -> Self {
+        MediaQuery::Min(value)
+    }
+}
+
 impl MediaQuery {
-    pub(crate) fn write_selector(&self, stream: &mut String) -> Result<(), StyleError> {
+    /// Returns the breakpoint this media query applies from, if it's a min-width query.
+    pub fn breakpoint(&self) -> Option<Breakpoint> {
+        match self {
+            MediaQuery::Min(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn write_selector<T>(
+        &self,
+        stream: &mut String,
+        options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
         match self {
-            MediaQuery::Sm => write!(stream, "@media(min-width:640px)")?,
-            MediaQuery::Md => write!(stream, "@media(min-width:768px)")?,
-            MediaQuery::Lg => write!(stream, "@media(min-width:1024px)")?,
-            MediaQuery::Xl => write!(stream, "@media(min-width:1280px)")?,
-            MediaQuery::Xxl => write!(stream, "@media(min-width:1536px)")?,
-            MediaQuery::Xxxl => write!(stream, "@media(min-width:1920px)")?,
+            MediaQuery::Min(x) => write!(stream, "@media(min-width:{}px)", options.breakpoint(*x))?,
+            // the exact complement of the min-width query, so ranges have no gaps
+            MediaQuery::Max(x) => write!(
+                stream,
+                "@media not all and (min-width:{}px)",
+                options.breakpoint(*x)
+            )?,
+            MediaQuery::Print => write!(stream, "@media print")?,
+            MediaQuery::Portrait => write!(stream, "@media(orientation:portrait)")?,
+            MediaQuery::Landscape => write!(stream, "@media(orientation:landscape)")?,
+            MediaQuery::MotionSafe => {
+                write!(stream, "@media(prefers-reduced-motion:no-preference)")?
+            }
+            MediaQuery::MotionReduce => write!(stream, "@media(prefers-reduced-motion:reduce)")?,
+            MediaQuery::ContrastMore => write!(stream, "@media(prefers-contrast:more)")?,
+            MediaQuery::Raw(x) => {
+                if x.contains(['{', '}', ';']) || x.contains("</") {
+                    return Err(StyleError::InvalidMediaQuery(
+                        x.to_string(),
+                        "braces, `;` and `</` would end the media query",
+                    ));
+                }
+                write!(stream, "@media {}", x)?
+            }
         };
 
         Ok(())
@@ -34,12 +126,22 @@ impl MediaQuery {
 
     pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
         match self {
-            MediaQuery::Sm => write!(stream, "sm")?,
-            MediaQuery::Md => write!(stream, "md")?,
-            MediaQuery::Lg => write!(stream, "lg")?,
-            MediaQuery::Xl => write!(stream, "xl")?,
-            MediaQuery::Xxl => write!(stream, "xxl")?,
-            MediaQuery::Xxxl => write!(stream, "xxxl")?,
+            MediaQuery::Min(x) => x.write_classname(stream)?,
+            MediaQuery::Max(x) => {
+                write!(stream, "max-")?;
+                x.write_classname(stream)?;
+            }
+            MediaQuery::Print => write!(stream, "print")?,
+            MediaQuery::Portrait => write!(stream, "portrait")?,
+            MediaQuery::Landscape => write!(stream, "landscape")?,
+            MediaQuery::MotionSafe => write!(stream, "motion-safe")?,
+            MediaQuery::MotionReduce => write!(stream, "motion-reduce")?,
+            MediaQuery::ContrastMore => write!(stream, "contrast-more")?,
+            MediaQuery::Raw(x) => {
+                write!(stream, "[@media_")?;
+                crate::style::write_arbitrary_classname(stream, x)?;
+                stream.push(']');
+            }
         };
 
         Ok(())
@@ -1,9 +1,15 @@
 mod align;
+mod animation;
+mod aspect_ratio;
 mod backdrop_filter;
+mod background;
 mod border;
 mod color;
+mod container_query;
+mod content;
 mod cursor;
 mod display;
+mod filter;
 mod flex;
 mod font;
 mod gap;
@@ -12,17 +18,26 @@ mod height;
 mod justify;
 mod margin;
 mod media_query;
+mod opacity;
 mod outline;
 mod padding;
 mod text;
+mod transform;
+mod transition;
 mod width;
 
 pub use align::*;
+pub use animation::*;
+pub use aspect_ratio::*;
 pub use backdrop_filter::*;
+pub use background::*;
 pub use border::*;
 pub use color::*;
+pub use container_query::*;
+pub use content::*;
 pub use cursor::*;
 pub use display::*;
+pub use filter::*;
 pub use flex::*;
 pub use font::*;
 pub use gap::*;
@@ -31,7 +46,10 @@ pub use height::*;
 pub use justify::*;
 pub use margin::*;
 pub use media_query::*;
+pub use opacity::*;
 pub use outline::*;
 pub use padding::*;
 pub use text::*;
+pub use transform::*;
+pub use transition::*;
 pub use width::*;
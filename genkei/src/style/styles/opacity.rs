@@ -0,0 +1,51 @@
+use crate::{Style, StyleError, Styleable};
+use std::fmt::Write;
+
+/// Represents the opacity style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Opacity {
+    /// opacity: X / 100;
+    Value(i32),
+}
+
+impl From<Opacity> for Style {
+    fn from(value: Opacity) -> Self {
+        Style::Opacity(value)
+    }
+}
+
+impl Opacity {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            Opacity::Value(x) => write!(stream, "opacity-{}", x)?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            Opacity::Value(x) => write!(stream, "opacity:{}", *x as f32 / 100.0)?,
+        };
+
+        Ok(())
+    }
+}
+
+impl<T> OpacityTrait for T where T: Styleable {}
+
+/// A trait for the opacity style attribute.
+pub trait OpacityTrait: Styleable {
+    /// opacity: X / 100;
+    #[inline]
+    fn opacity(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Opacity::Value(value.into()))
+    }
+}
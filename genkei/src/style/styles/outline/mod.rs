@@ -2,7 +2,7 @@ mod outline_style;
 
 pub use outline_style::*;
 
-use crate::{Color, Style, StyleBuilder, StyleError, Styleable};
+use crate::{AnyColor, ColorValue, Style, StyleBuilder, StyleError, Styleable};
 use std::fmt::Write;
 
 /// Represents the outline style.
@@ -13,7 +13,7 @@ pub enum Outline {
     /// outline-width: value
     Width(i32),
     /// outline-color: value
-    Color(Color),
+    Color(AnyColor),
 }
 
 impl From<Outline> for Style {
@@ -52,7 +52,7 @@ impl Outline {
             }
             Outline::Color(value) => {
                 write!(stream, "outline-color:")?;
-                value.write_css_value(stream, options)?;
+                value.write_color_value(stream, options)?;
             }
         };
 
@@ -75,7 +75,7 @@ pub trait OutlineTrait: Styleable {
     }
 
     #[inline]
-    fn outline_color(self, value: impl Into<Color>) -> Self::Output {
+    fn outline_color(self, value: impl Into<AnyColor>) -> Self::Output {
         self.style(Outline::Color(value.into()))
     }
 
@@ -84,7 +84,7 @@ pub trait OutlineTrait: Styleable {
         self,
         style: impl Into<OutlineStyle>,
         width: impl Into<i32>,
-        color: impl Into<Color>,
+        color: impl Into<AnyColor>,
     ) -> Self::Output {
         self.styles(
             StyleBuilder::new()
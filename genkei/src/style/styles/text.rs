@@ -14,7 +14,7 @@ pub enum TextAlign {
 
 impl From<TextAlign> for Style {
     fn from(value: TextAlign) -> Self {
-        value.into()
+        Style::TextAlign(value)
     }
 }
 
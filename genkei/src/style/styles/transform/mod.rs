@@ -0,0 +1,218 @@
+mod transform_origin;
+
+pub use transform_origin::*;
+
+use crate::{Style, StyleError, Styleable};
+use std::fmt::Write;
+
+/// The `transform` value shared by all transform utilities. Every utility only sets its own
+/// `--tw-*` variable, so several transforms on the same element compose instead of
+/// overwriting each other. The variables are reset for every element in `reset.css`, the
+/// fallbacks cover stylesheets rendered without the reset.
+const TRANSFORM: &str = "transform:\
+translate3d(var(--tw-translate-x,0),var(--tw-translate-y,0),var(--tw-translate-z,0)) \
+rotateX(var(--tw-rotate-x,0)) \
+rotateY(var(--tw-rotate-y,0)) \
+rotate(var(--tw-rotate,0)) \
+skewX(var(--tw-skew-x,0)) \
+skewY(var(--tw-skew-y,0)) \
+scaleX(var(--tw-scale-x,1)) \
+scaleY(var(--tw-scale-y,1))";
+
+/// Represents the transform styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Transform {
+    /// --tw-translate-x: spacing(X);
+    TranslateX(i32),
+    /// --tw-translate-y: spacing(X);
+    TranslateY(i32),
+    /// --tw-translate-z: spacing(X);
+    TranslateZ(i32),
+    /// --tw-rotate: Xdeg;
+    Rotate(i32),
+    /// --tw-rotate-x: Xdeg;
+    RotateX(i32),
+    /// --tw-rotate-y: Xdeg;
+    RotateY(i32),
+    /// --tw-scale-x: X / 100;
+    /// --tw-scale-y: X / 100;
+    Scale(i32),
+    /// --tw-scale-x: X / 100;
+    ScaleX(i32),
+    /// --tw-scale-y: X / 100;
+    ScaleY(i32),
+    /// --tw-skew-x: Xdeg;
+    SkewX(i32),
+    /// --tw-skew-y: Xdeg;
+    SkewY(i32),
+    /// transform-origin: value;
+    Origin(TransformOrigin),
+    /// perspective: Xpx;
+    Perspective(i32),
+}
+
+impl From<Transform> for Style {
+    fn from(value: Transform) -> Self {
+        Style::Transform(value)
+    }
+}
+
+impl Transform {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            Transform::TranslateX(x) => write!(stream, "translate-x-{}", x)?,
+            Transform::TranslateY(x) => write!(stream, "translate-y-{}", x)?,
+            Transform::TranslateZ(x) => write!(stream, "translate-z-{}", x)?,
+            Transform::Rotate(x) => write!(stream, "rotate-{}", x)?,
+            Transform::RotateX(x) => write!(stream, "rotate-x-{}", x)?,
+            Transform::RotateY(x) => write!(stream, "rotate-y-{}", x)?,
+            Transform::Scale(x) => write!(stream, "scale-{}", x)?,
+            Transform::ScaleX(x) => write!(stream, "scale-x-{}", x)?,
+            Transform::ScaleY(x) => write!(stream, "scale-y-{}", x)?,
+            Transform::SkewX(x) => write!(stream, "skew-x-{}", x)?,
+            Transform::SkewY(x) => write!(stream, "skew-y-{}", x)?,
+            Transform::Origin(x) => x.write_classname(stream)?,
+            Transform::Perspective(x) => write!(stream, "perspective-{}", x)?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            Transform::Origin(x) => x.write_css_statement(stream, options)?,
+            Transform::Perspective(x) => write!(stream, "perspective:{}px", x)?,
+            _ => {
+                self.write_variable(stream, options)?;
+                stream.push(';');
+                stream.push_str(TRANSFORM);
+            }
+        };
+
+        Ok(())
+    }
+
+    fn write_variable<T>(&self, stream: &mut String, options: &T) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            Transform::TranslateX(x) => {
+                write!(stream, "--tw-translate-x:")?;
+                options.spacing(stream, *x)?;
+            }
+            Transform::TranslateY(x) => {
+                write!(stream, "--tw-translate-y:")?;
+                options.spacing(stream, *x)?;
+            }
+            Transform::TranslateZ(x) => {
+                write!(stream, "--tw-translate-z:")?;
+                options.spacing(stream, *x)?;
+            }
+            Transform::Rotate(x) => write!(stream, "--tw-rotate:{}deg", x)?,
+            Transform::RotateX(x) => write!(stream, "--tw-rotate-x:{}deg", x)?,
+            Transform::RotateY(x) => write!(stream, "--tw-rotate-y:{}deg", x)?,
+            Transform::Scale(x) => {
+                let scale = *x as f32 / 100.0;
+                write!(stream, "--tw-scale-x:{};--tw-scale-y:{}", scale, scale)?
+            }
+            Transform::ScaleX(x) => write!(stream, "--tw-scale-x:{}", *x as f32 / 100.0)?,
+            Transform::ScaleY(x) => write!(stream, "--tw-scale-y:{}", *x as f32 / 100.0)?,
+            Transform::SkewX(x) => write!(stream, "--tw-skew-x:{}deg", x)?,
+            Transform::SkewY(x) => write!(stream, "--tw-skew-y:{}deg", x)?,
+            Transform::Origin(_) | Transform::Perspective(_) => {}
+        };
+
+        Ok(())
+    }
+}
+
+impl<T> TransformTrait for T where T: Styleable {}
+
+/// Transform style attributes.
+pub trait TransformTrait: Styleable {
+    /// Translate along the x-axis by spacing(X).
+    #[inline]
+    fn translate_x(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::TranslateX(value.into()))
+    }
+
+    /// Translate along the y-axis by spacing(X).
+    #[inline]
+    fn translate_y(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::TranslateY(value.into()))
+    }
+
+    /// Translate along the z-axis by spacing(X).
+    #[inline]
+    fn translate_z(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::TranslateZ(value.into()))
+    }
+
+    /// Rotate by X degrees.
+    #[inline]
+    fn rotate(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::Rotate(value.into()))
+    }
+
+    /// Rotate around the x-axis by X degrees.
+    #[inline]
+    fn rotate_x(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::RotateX(value.into()))
+    }
+
+    /// Rotate around the y-axis by X degrees.
+    #[inline]
+    fn rotate_y(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::RotateY(value.into()))
+    }
+
+    /// Scale by X percent, e.g. `95` is `0.95`.
+    #[inline]
+    fn scale(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::Scale(value.into()))
+    }
+
+    /// Scale along the x-axis by X percent.
+    #[inline]
+    fn scale_x(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::ScaleX(value.into()))
+    }
+
+    /// Scale along the y-axis by X percent.
+    #[inline]
+    fn scale_y(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::ScaleY(value.into()))
+    }
+
+    /// Skew along the x-axis by X degrees.
+    #[inline]
+    fn skew_x(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::SkewX(value.into()))
+    }
+
+    /// Skew along the y-axis by X degrees.
+    #[inline]
+    fn skew_y(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::SkewY(value.into()))
+    }
+
+    /// transform-origin: value;
+    #[inline]
+    fn origin(self, value: impl Into<TransformOrigin>) -> Self::Output {
+        self.style(Transform::Origin(value.into()))
+    }
+
+    /// perspective: Xpx;
+    #[inline]
+    fn perspective(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transform::Perspective(value.into()))
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{Style, StyleError, Transform};
+use std::fmt::Write;
+
+/// Represents the transform-origin style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransformOrigin {
+    /// transform-origin: center
+    Center,
+    /// transform-origin: top
+    Top,
+    /// transform-origin: top right
+    TopRight,
+    /// transform-origin: right
+    Right,
+    /// transform-origin: bottom right
+    BottomRight,
+    /// transform-origin: bottom
+    Bottom,
+    /// transform-origin: bottom left
+    BottomLeft,
+    /// transform-origin: left
+    Left,
+    /// transform-origin: top left
+    TopLeft,
+}
+
+impl From<TransformOrigin> for Style {
+    fn from(value: TransformOrigin) -> Self {
+        Transform::Origin(value).into()
+    }
+}
+
+impl TransformOrigin {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            TransformOrigin::Center => write!(stream, "origin-center")?,
+            TransformOrigin::Top => write!(stream, "origin-top")?,
+            TransformOrigin::TopRight => write!(stream, "origin-top-right")?,
+            TransformOrigin::Right => write!(stream, "origin-right")?,
+            TransformOrigin::BottomRight => write!(stream, "origin-bottom-right")?,
+            TransformOrigin::Bottom => write!(stream, "origin-bottom")?,
+            TransformOrigin::BottomLeft => write!(stream, "origin-bottom-left")?,
+            TransformOrigin::Left => write!(stream, "origin-left")?,
+            TransformOrigin::TopLeft => write!(stream, "origin-top-left")?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            TransformOrigin::Center => write!(stream, "transform-origin:center")?,
+            TransformOrigin::Top => write!(stream, "transform-origin:top")?,
+            TransformOrigin::TopRight => write!(stream, "transform-origin:top right")?,
+            TransformOrigin::Right => write!(stream, "transform-origin:right")?,
+            TransformOrigin::BottomRight => write!(stream, "transform-origin:bottom right")?,
+            TransformOrigin::Bottom => write!(stream, "transform-origin:bottom")?,
+            TransformOrigin::BottomLeft => write!(stream, "transform-origin:bottom left")?,
+            TransformOrigin::Left => write!(stream, "transform-origin:left")?,
+            TransformOrigin::TopLeft => write!(stream, "transform-origin:top left")?,
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{Style, StyleError, Styleable};
+use std::fmt::Write;
+
+const DEFAULT_TIMING: &str = "transition-timing-function:cubic-bezier(0.4,0,0.2,1)";
+const DEFAULT_DURATION: &str = "transition-duration:150ms";
+
+/// Represents the transition-property style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransitionProperty {
+    /// transition-property: none
+    None,
+    /// transition-property: all
+    All,
+    /// transition-property: color, background-color, border-color, ...
+    Colors,
+    /// transition-property: opacity
+    Opacity,
+    /// transition-property: transform
+    Transform,
+}
+
+impl From<TransitionProperty> for Style {
+    fn from(value: TransitionProperty) -> Self {
+        Transition::Property(value).into()
+    }
+}
+
+/// Represents the transition-timing-function style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransitionTiming {
+    /// transition-timing-function: linear
+    Linear,
+    /// transition-timing-function: cubic-bezier(0.4, 0, 1, 1)
+    In,
+    /// transition-timing-function: cubic-bezier(0, 0, 0.2, 1)
+    Out,
+    /// transition-timing-function: cubic-bezier(0.4, 0, 0.2, 1)
+    InOut,
+}
+
+impl From<TransitionTiming> for Style {
+    fn from(value: TransitionTiming) -> Self {
+        Transition::Timing(value).into()
+    }
+}
+
+/// Represents the transition styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Transition {
+    /// transition-property: value;
+    /// transition-timing-function: cubic-bezier(0.4, 0, 0.2, 1);
+    /// transition-duration: 150ms;
+    Property(TransitionProperty),
+    /// transition-duration: Xms;
+    Duration(i32),
+    /// transition-timing-function: value;
+    Timing(TransitionTiming),
+    /// transition-delay: Xms;
+    Delay(i32),
+}
+
+impl From<Transition> for Style {
+    fn from(value: Transition) -> Self {
+        Style::Transition(value)
+    }
+}
+
+impl Transition {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            Transition::Property(x) => match x {
+                TransitionProperty::None => write!(stream, "transition-none")?,
+                TransitionProperty::All => write!(stream, "transition-all")?,
+                TransitionProperty::Colors => write!(stream, "transition-colors")?,
+                TransitionProperty::Opacity => write!(stream, "transition-opacity")?,
+                TransitionProperty::Transform => write!(stream, "transition-transform")?,
+            },
+            Transition::Duration(x) => write!(stream, "duration-{}", x)?,
+            Transition::Timing(x) => match x {
+                TransitionTiming::Linear => write!(stream, "ease-linear")?,
+                TransitionTiming::In => write!(stream, "ease-in")?,
+                TransitionTiming::Out => write!(stream, "ease-out")?,
+                TransitionTiming::InOut => write!(stream, "ease-in-out")?,
+            },
+            Transition::Delay(x) => write!(stream, "delay-{}", x)?,
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        _options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            Transition::Property(x) => {
+                let property = match x {
+                    TransitionProperty::None => "none",
+                    TransitionProperty::All => "all",
+                    TransitionProperty::Colors => {
+                        "color,background-color,border-color,outline-color,text-decoration-color,fill,stroke"
+                    }
+                    TransitionProperty::Opacity => "opacity",
+                    TransitionProperty::Transform => "transform",
+                };
+                write!(stream, "transition-property:{}", property)?;
+                if *x != TransitionProperty::None {
+                    write!(stream, ";{};{}", DEFAULT_TIMING, DEFAULT_DURATION)?;
+                }
+            }
+            Transition::Duration(x) => write!(stream, "transition-duration:{}ms", x)?,
+            Transition::Timing(x) => match x {
+                TransitionTiming::Linear => write!(stream, "transition-timing-function:linear")?,
+                TransitionTiming::In => {
+                    write!(stream, "transition-timing-function:cubic-bezier(0.4,0,1,1)")?
+                }
+                TransitionTiming::Out => {
+                    write!(stream, "transition-timing-function:cubic-bezier(0,0,0.2,1)")?
+                }
+                TransitionTiming::InOut => write!(
+                    stream,
+                    "transition-timing-function:cubic-bezier(0.4,0,0.2,1)"
+                )?,
+            },
+            Transition::Delay(x) => write!(stream, "transition-delay:{}ms", x)?,
+        };
+
+        Ok(())
+    }
+}
+
+impl<T> TransitionTrait for T where T: Styleable {}
+
+/// Transition style attributes.
+pub trait TransitionTrait: Styleable {
+    #[inline]
+    fn transition(self, value: impl Into<TransitionProperty>) -> Self::Output {
+        self.style(Transition::Property(value.into()))
+    }
+
+    #[inline]
+    fn transition_none(self) -> Self::Output {
+        self.transition(TransitionProperty::None)
+    }
+
+    #[inline]
+    fn transition_all(self) -> Self::Output {
+        self.transition(TransitionProperty::All)
+    }
+
+    #[inline]
+    fn transition_colors(self) -> Self::Output {
+        self.transition(TransitionProperty::Colors)
+    }
+
+    #[inline]
+    fn transition_opacity(self) -> Self::Output {
+        self.transition(TransitionProperty::Opacity)
+    }
+
+    #[inline]
+    fn transition_transform(self) -> Self::Output {
+        self.transition(TransitionProperty::Transform)
+    }
+
+    /// transition-duration: Xms;
+    #[inline]
+    fn duration(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transition::Duration(value.into()))
+    }
+
+    #[inline]
+    fn ease(self, value: impl Into<TransitionTiming>) -> Self::Output {
+        self.style(Transition::Timing(value.into()))
+    }
+
+    #[inline]
+    fn ease_linear(self) -> Self::Output {
+        self.ease(TransitionTiming::Linear)
+    }
+
+    #[inline]
+    fn ease_in(self) -> Self::Output {
+        self.ease(TransitionTiming::In)
+    }
+
+    #[inline]
+    fn ease_out(self) -> Self::Output {
+        self.ease(TransitionTiming::Out)
+    }
+
+    #[inline]
+    fn ease_in_out(self) -> Self::Output {
+        self.ease(TransitionTiming::InOut)
+    }
+
+    /// transition-delay: Xms;
+    #[inline]
+    fn delay(self, value: impl Into<i32>) -> Self::Output {
+        self.style(Transition::Delay(value.into()))
+    }
+}
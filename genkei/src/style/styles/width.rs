@@ -1,4 +1,4 @@
-use crate::{Style, StyleError, Styleable};
+use crate::{Breakpoint, Style, StyleError, Styleable};
 use std::fmt::Write;
 
 /// Represents the width style.
@@ -126,6 +126,149 @@ impl MinWidth {
     }
 }
 
+/// Named container sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ContainerSize {
+    /// xs: 20rem
+    Xs,
+    /// sm: 24rem
+    Sm,
+    /// md: 28rem
+    Md,
+    /// lg: 32rem
+    Lg,
+    /// xl: 36rem
+    Xl,
+    /// 2xl: 42rem
+    Xxl,
+    /// 3xl: 48rem
+    Xxxl,
+    /// 4xl: 56rem
+    Xxxxl,
+    /// 5xl: 64rem
+    Xxxxxl,
+    /// 6xl: 72rem
+    Xxxxxxl,
+    /// 7xl: 80rem
+    Xxxxxxxl,
+}
+
+impl ContainerSize {
+    /// Returns the container width in rem.
+    pub fn rem(&self) -> i32 {
+        match self {
+            ContainerSize::Xs => 20,
+            ContainerSize::Sm => 24,
+            ContainerSize::Md => 28,
+            ContainerSize::Lg => 32,
+            ContainerSize::Xl => 36,
+            ContainerSize::Xxl => 42,
+            ContainerSize::Xxxl => 48,
+            ContainerSize::Xxxxl => 56,
+            ContainerSize::Xxxxxl => 64,
+            ContainerSize::Xxxxxxl => 72,
+            ContainerSize::Xxxxxxxl => 80,
+        }
+    }
+
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            ContainerSize::Xs => write!(stream, "xs")?,
+            ContainerSize::Sm => write!(stream, "sm")?,
+            ContainerSize::Md => write!(stream, "md")?,
+            ContainerSize::Lg => write!(stream, "lg")?,
+            ContainerSize::Xl => write!(stream, "xl")?,
+            ContainerSize::Xxl => write!(stream, "2xl")?,
+            ContainerSize::Xxxl => write!(stream, "3xl")?,
+            ContainerSize::Xxxxl => write!(stream, "4xl")?,
+            ContainerSize::Xxxxxl => write!(stream, "5xl")?,
+            ContainerSize::Xxxxxxl => write!(stream, "6xl")?,
+            ContainerSize::Xxxxxxxl => write!(stream, "7xl")?,
+        };
+
+        Ok(())
+    }
+}
+
+/// Represents the max-width style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MaxWidth {
+    /// max-width: spacing(X);
+    Value(i32),
+    /// max-width: none;
+    None,
+    /// max-width: 100%;
+    Full,
+    /// max-width: min-content;
+    MinContent,
+    /// max-width: max-content;
+    MaxContent,
+    /// max-width: fit-content;
+    FitContent,
+    /// max-width: 65ch;
+    Prose,
+    /// max-width: container size;
+    Container(ContainerSize),
+    /// max-width: breakpoint;
+    Screen(Breakpoint),
+}
+
+impl From<MaxWidth> for Style {
+    fn from(value: MaxWidth) -> Self {
+        Style::MaxWidth(value)
+    }
+}
+
+impl MaxWidth {
+    pub(crate) fn write_classname(&self, stream: &mut String) -> Result<(), StyleError> {
+        match self {
+            MaxWidth::Value(x) => write!(stream, "max-w-{}", x)?,
+            MaxWidth::None => write!(stream, "max-w-none")?,
+            MaxWidth::Full => write!(stream, "max-w-full")?,
+            MaxWidth::MinContent => write!(stream, "max-w-min")?,
+            MaxWidth::MaxContent => write!(stream, "max-w-max")?,
+            MaxWidth::FitContent => write!(stream, "max-w-fit")?,
+            MaxWidth::Prose => write!(stream, "max-w-prose")?,
+            MaxWidth::Container(x) => {
+                write!(stream, "max-w-")?;
+                x.write_classname(stream)?;
+            }
+            MaxWidth::Screen(x) => {
+                write!(stream, "max-w-screen-")?;
+                x.write_classname(stream)?;
+            }
+        };
+
+        Ok(())
+    }
+
+    pub(crate) fn write_css_statement<T>(
+        &self,
+        stream: &mut String,
+        options: &T,
+    ) -> Result<(), StyleError>
+    where
+        T: crate::StyleOptions,
+    {
+        match self {
+            MaxWidth::Value(x) => {
+                write!(stream, "max-width:")?;
+                options.spacing(stream, *x)?;
+            }
+            MaxWidth::None => write!(stream, "max-width:none")?,
+            MaxWidth::Full => write!(stream, "max-width:100%")?,
+            MaxWidth::MinContent => write!(stream, "max-width:min-content")?,
+            MaxWidth::MaxContent => write!(stream, "max-width:max-content")?,
+            MaxWidth::FitContent => write!(stream, "max-width:fit-content")?,
+            MaxWidth::Prose => write!(stream, "max-width:65ch")?,
+            MaxWidth::Container(x) => write!(stream, "max-width:{}rem", x.rem())?,
+            MaxWidth::Screen(x) => write!(stream, "max-width:{}px", options.breakpoint(*x))?,
+        };
+
+        Ok(())
+    }
+}
+
 impl<T> WidthTrait for T where T: Styleable {}
 
 /// Width style attributes.
@@ -201,4 +344,58 @@ pub trait WidthTrait: Styleable {
     fn min_w_max(self) -> Self::Output {
         self.style(MinWidth::MaxContent)
     }
+
+    /// max-width: spacing(X);
+    #[inline]
+    fn max_w(self, value: impl Into<i32>) -> Self::Output {
+        self.style(MaxWidth::Value(value.into()))
+    }
+
+    /// max-width: none;
+    #[inline]
+    fn max_w_none(self) -> Self::Output {
+        self.style(MaxWidth::None)
+    }
+
+    /// max-width: 100%;
+    #[inline]
+    fn max_w_full(self) -> Self::Output {
+        self.style(MaxWidth::Full)
+    }
+
+    /// max-width: fit-content;
+    #[inline]
+    fn max_w_fit(self) -> Self::Output {
+        self.style(MaxWidth::FitContent)
+    }
+
+    /// max-width: min-content;
+    #[inline]
+    fn max_w_min(self) -> Self::Output {
+        self.style(MaxWidth::MinContent)
+    }
+
+    /// max-width: max-content;
+    #[inline]
+    fn max_w_max(self) -> Self::Output {
+        self.style(MaxWidth::MaxContent)
+    }
+
+    /// max-width: 65ch;
+    #[inline]
+    fn max_w_prose(self) -> Self::Output {
+        self.style(MaxWidth::Prose)
+    }
+
+    /// max-width: container size, e.g. `max-w-7xl`;
+    #[inline]
+    fn max_w_container(self, value: impl Into<ContainerSize>) -> Self::Output {
+        self.style(MaxWidth::Container(value.into()))
+    }
+
+    /// max-width: breakpoint, e.g. `max-w-screen-lg`;
+    #[inline]
+    fn max_w_screen(self, value: impl Into<Breakpoint>) -> Self::Output {
+        self.style(MaxWidth::Screen(value.into()))
+    }
 }
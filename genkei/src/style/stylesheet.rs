@@ -0,0 +1,55 @@
+use crate::stable_hash;
+
+/// Rendered css meant to be served as an external stylesheet, e.g. `app.3f9c1a2b5e7d4c60.css`.
+///
+/// The file name contains a hash of the css, so the file can be cached forever: changed css
+/// gets a new file name, the same css always gets the same one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stylesheet {
+    css: String,
+    hash: u64,
+    filename: String,
+}
+
+impl Stylesheet {
+    /// Creates the stylesheet `{name}.{hash}.css`, the hash is the [`stable_hash`] of the css in
+    /// 16 hex digits.
+    pub fn new(name: &str, css: String) -> Self {
+        let hash = stable_hash(&css);
+        let filename = format!("{}.{:016x}.css", name, hash);
+        Self {
+            css,
+            hash,
+            filename,
+        }
+    }
+
+    pub fn css(&self) -> &str {
+        &self.css
+    }
+
+    pub fn into_css(self) -> String {
+        self.css
+    }
+
+    /// The [`stable_hash`] of the css.
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// The file name with the content hash, e.g. `app.3f9c1a2b5e7d4c60.css`.
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+
+    /// Creates `<link rel=stylesheet href=..>` referencing the file, `prefix` is the path the
+    /// file is served from, e.g. `/assets/`.
+    #[cfg(feature = "html")]
+    pub fn link(&self, prefix: &str) -> crate::Tag {
+        use crate::{Attributes, HtmlAttribute};
+
+        crate::link()
+            .attr_kv("rel", "stylesheet")
+            .href(format!("{}{}", prefix, self.filename))
+    }
+}
@@ -0,0 +1,297 @@
+use super::renderer::write_arbitrary_classname;
+use crate::{
+    AnyColor, AspectRatio, Background, Border, BorderWidth, Breakpoint, Color, ColorStyle,
+    ColorValue, ComplexColor, Display, DropShadow, Filter, Flex, FlexDirection, FlexWrap, Font,
+    FontFamily, FontStyle, Gap, Margin, MaxWidth, MediaQuery, Outline, OutlineStyle, Style,
+    StyleError, StyleOptions, Transform,
+};
+use std::fmt::Write;
+
+/// Writes Tailwind's class name of a style and returns `true`, or returns `false` without
+/// writing anything if it's the same as the genkei class name. Variants are handled by the
+/// renderer.
+pub(crate) fn write_classname<T: StyleOptions>(
+    style: &Style,
+    stream: &mut String,
+    options: &T,
+) -> Result<bool, StyleError> {
+    match style {
+        Style::Margin(x) => write_margin(stream, x)?,
+        Style::MaxWidth(MaxWidth::Screen(x)) => {
+            write!(stream, "max-w-screen-{}", breakpoint_name(*x))?
+        }
+        Style::AspectRatio(AspectRatio::Ratio(x, y)) => write!(stream, "aspect-[{}/{}]", x, y)?,
+        Style::SimpleColor(x) => match x {
+            ColorStyle::Foreground(x) => write_color(stream, "text", &(*x).into(), options)?,
+            ColorStyle::Background(x) => write_color(stream, "bg", &(*x).into(), options)?,
+        },
+        Style::ComplexColor(x) => match x {
+            ColorStyle::Foreground(x) => write_color(stream, "text", &x.clone().into(), options)?,
+            ColorStyle::Background(x) => write_color(stream, "bg", &x.clone().into(), options)?,
+        },
+        Style::Background(Background::From(x)) => write_color(stream, "from", x, options)?,
+        Style::Background(Background::Via(x)) => write_color(stream, "via", x, options)?,
+        Style::Background(Background::To(x)) => write_color(stream, "to", x, options)?,
+        Style::Font(x) => write_font(stream, x)?,
+        Style::Display(Display::None) => stream.push_str("hidden"),
+        Style::Flex(x) => write_flex(stream, x)?,
+        Style::Border(x) => write_border(stream, x, options)?,
+        Style::Outline(x) => {
+            if !write_outline(stream, x, options)? {
+                return Ok(false);
+            }
+        }
+        Style::Filter(x) => write_filter(stream, x)?,
+        Style::BackdropFilter(x) => {
+            stream.push_str("backdrop-");
+            write_filter(stream, &x.filter())?;
+        }
+        Style::Gap(Gap::Column(x)) => write!(stream, "gap-x-{}", x)?,
+        Style::Gap(Gap::Row(x)) => write!(stream, "gap-y-{}", x)?,
+        Style::Transform(x) => {
+            if !write_transform(stream, x)? {
+                return Ok(false);
+            }
+        }
+        _ => return Ok(false),
+    };
+
+    Ok(true)
+}
+
+/// Writes the class name prefix of a media query, without the trailing `:`.
+pub(crate) fn write_media_query(mq: &MediaQuery, stream: &mut String) -> Result<(), StyleError> {
+    match mq {
+        MediaQuery::Min(x) => stream.push_str(breakpoint_name(*x)),
+        MediaQuery::Max(x) => write!(stream, "max-{}", breakpoint_name(*x))?,
+        mq => mq.write_classname(stream)?,
+    };
+
+    Ok(())
+}
+
+fn breakpoint_name(breakpoint: Breakpoint) -> &'static str {
+    match breakpoint {
+        Breakpoint::Sm => "sm",
+        Breakpoint::Md => "md",
+        Breakpoint::Lg => "lg",
+        Breakpoint::Xl => "xl",
+        Breakpoint::Xxl => "2xl",
+        Breakpoint::Xxxl => "3xl",
+    }
+}
+
+/// Writes `-prefix-X` for negative values, e.g. `-mt-2`.
+fn write_signed(stream: &mut String, prefix: &str, value: i32) -> Result<(), StyleError> {
+    if value < 0 {
+        stream.push('-');
+    }
+    write!(stream, "{}-{}", prefix, value.unsigned_abs())?;
+    Ok(())
+}
+
+fn write_margin(stream: &mut String, margin: &Margin) -> Result<(), StyleError> {
+    match *margin {
+        Margin::All(x) => write_signed(stream, "m", x),
+        Margin::Top(x) => write_signed(stream, "mt", x),
+        Margin::Right(x) => write_signed(stream, "mr", x),
+        Margin::Bottom(x) => write_signed(stream, "mb", x),
+        Margin::Left(x) => write_signed(stream, "ml", x),
+        Margin::X(x) => write_signed(stream, "mx", x),
+        Margin::Y(x) => write_signed(stream, "my", x),
+    }
+}
+
+fn write_transform(stream: &mut String, transform: &Transform) -> Result<bool, StyleError> {
+    match *transform {
+        Transform::TranslateX(x) => write_signed(stream, "translate-x", x)?,
+        Transform::TranslateY(x) => write_signed(stream, "translate-y", x)?,
+        Transform::TranslateZ(x) => write_signed(stream, "translate-z", x)?,
+        Transform::Rotate(x) => write_signed(stream, "rotate", x)?,
+        Transform::RotateX(x) => write_signed(stream, "rotate-x", x)?,
+        Transform::RotateY(x) => write_signed(stream, "rotate-y", x)?,
+        Transform::SkewX(x) => write_signed(stream, "skew-x", x)?,
+        Transform::SkewY(x) => write_signed(stream, "skew-y", x)?,
+        _ => return Ok(false),
+    };
+
+    Ok(true)
+}
+
+/// Writes palette colors by name and other colors as arbitrary values, e.g. `text-red-500`
+/// and `text-[rgb(1,2,3)]`.
+fn write_color<T: StyleOptions>(
+    stream: &mut String,
+    prefix: &str,
+    color: &AnyColor,
+    options: &T,
+) -> Result<(), StyleError> {
+    write!(stream, "{}-", prefix)?;
+    match color {
+        AnyColor::Simple(x) if x.is_palette() || *x == Color::Transparent => {
+            x.write_color_name(stream)?
+        }
+        AnyColor::Complex(ComplexColor::CurrentColor) => stream.push_str("current"),
+        _ => {
+            let mut value = String::with_capacity(32);
+            color.write_color_value(&mut value, options)?;
+            stream.push('[');
+            // `text-[var(--x)]` would be read as a font size
+            if value.starts_with("var(") {
+                stream.push_str("color:");
+            }
+            write_arbitrary_classname(stream, &value)?;
+            stream.push(']');
+        }
+    };
+
+    Ok(())
+}
+
+fn write_font(stream: &mut String, font: &Font) -> Result<(), StyleError> {
+    match font {
+        Font::Size(x) => x.write_classname(stream)?,
+        Font::Weight(x) => match x {
+            100 => stream.push_str("font-thin"),
+            200 => stream.push_str("font-extralight"),
+            300 => stream.push_str("font-light"),
+            400 => stream.push_str("font-normal"),
+            500 => stream.push_str("font-medium"),
+            600 => stream.push_str("font-semibold"),
+            700 => stream.push_str("font-bold"),
+            800 => stream.push_str("font-extrabold"),
+            900 => stream.push_str("font-black"),
+            x => write!(stream, "font-[{}]", x)?,
+        },
+        Font::Family(x) => match x {
+            FontFamily::Sans => stream.push_str("font-sans"),
+            FontFamily::Serif => stream.push_str("font-serif"),
+            FontFamily::Mono => stream.push_str("font-mono"),
+        },
+        Font::Style(x) => match x {
+            FontStyle::Normal => stream.push_str("not-italic"),
+            FontStyle::Italic => stream.push_str("italic"),
+        },
+    };
+
+    Ok(())
+}
+
+fn write_flex(stream: &mut String, flex: &Flex) -> Result<(), StyleError> {
+    match flex {
+        Flex::Direction(x) => match x {
+            FlexDirection::Row => stream.push_str("flex-row"),
+            FlexDirection::RowReverse => stream.push_str("flex-row-reverse"),
+            FlexDirection::Column => stream.push_str("flex-col"),
+            FlexDirection::ColumnReverse => stream.push_str("flex-col-reverse"),
+        },
+        Flex::Wrap(x) => match x {
+            FlexWrap::NoWrap => stream.push_str("flex-nowrap"),
+            FlexWrap::Wrap => stream.push_str("flex-wrap"),
+            FlexWrap::WrapReverse => stream.push_str("flex-wrap-reverse"),
+        },
+        Flex::Grow(1) => stream.push_str("grow"),
+        Flex::Grow(0) => stream.push_str("grow-0"),
+        Flex::Grow(x) => write!(stream, "grow-[{}]", x)?,
+        Flex::Shrink(1) => stream.push_str("shrink"),
+        Flex::Shrink(0) => stream.push_str("shrink-0"),
+        Flex::Shrink(x) => write!(stream, "shrink-[{}]", x)?,
+    };
+
+    Ok(())
+}
+
+fn write_border<T: StyleOptions>(
+    stream: &mut String,
+    border: &Border,
+    options: &T,
+) -> Result<(), StyleError> {
+    match border {
+        Border::Width(x) => {
+            let (prefix, value) = match *x {
+                BorderWidth::All(x) => ("border", x),
+                BorderWidth::X(x) => ("border-x", x),
+                BorderWidth::Y(x) => ("border-y", x),
+                BorderWidth::Top(x) => ("border-t", x),
+                BorderWidth::Right(x) => ("border-r", x),
+                BorderWidth::Bottom(x) => ("border-b", x),
+                BorderWidth::Left(x) => ("border-l", x),
+            };
+            stream.push_str(prefix);
+            if value != 1 {
+                write!(stream, "-{}", value)?;
+            }
+        }
+        Border::Color(x) => write_color(stream, "border", x, options)?,
+        Border::Radius(x) => {
+            // the named radii are matched by their css value
+            let mut value = String::with_capacity(16);
+            options.radius(&mut value, *x)?;
+            match value.as_str() {
+                "0" | "0px" | "0rem" => stream.push_str("rounded-none"),
+                "0.125rem" => stream.push_str("rounded-sm"),
+                "0.25rem" => stream.push_str("rounded"),
+                "0.375rem" => stream.push_str("rounded-md"),
+                "0.5rem" => stream.push_str("rounded-lg"),
+                "0.75rem" => stream.push_str("rounded-xl"),
+                "1rem" => stream.push_str("rounded-2xl"),
+                "1.5rem" => stream.push_str("rounded-3xl"),
+                "9999px" => stream.push_str("rounded-full"),
+                value => {
+                    stream.push_str("rounded-[");
+                    write_arbitrary_classname(stream, value)?;
+                    stream.push(']');
+                }
+            }
+        }
+    };
+
+    Ok(())
+}
+
+fn write_outline<T: StyleOptions>(
+    stream: &mut String,
+    outline: &Outline,
+    options: &T,
+) -> Result<bool, StyleError> {
+    match outline {
+        Outline::Style(x) => match x {
+            OutlineStyle::Solid => stream.push_str("outline"),
+            OutlineStyle::Dashed => stream.push_str("outline-dashed"),
+            OutlineStyle::Dotted => stream.push_str("outline-dotted"),
+            OutlineStyle::Double => stream.push_str("outline-double"),
+            OutlineStyle::None => stream.push_str("outline-none"),
+            _ => return Ok(false),
+        },
+        Outline::Width(x) => write!(stream, "outline-{}", x)?,
+        Outline::Color(x) => write_color(stream, "outline", x, options)?,
+    };
+
+    Ok(true)
+}
+
+fn write_filter(stream: &mut String, filter: &Filter) -> Result<(), StyleError> {
+    match *filter {
+        Filter::Blur(x) => match x {
+            0 => stream.push_str("blur-none"),
+            4 => stream.push_str("blur-sm"),
+            8 => stream.push_str("blur"),
+            12 => stream.push_str("blur-md"),
+            16 => stream.push_str("blur-lg"),
+            24 => stream.push_str("blur-xl"),
+            40 => stream.push_str("blur-2xl"),
+            64 => stream.push_str("blur-3xl"),
+            x => write!(stream, "blur-[{}px]", x)?,
+        },
+        Filter::Grayscale(100) => stream.push_str("grayscale"),
+        Filter::Grayscale(x) if x != 0 => write!(stream, "grayscale-[{}%]", x)?,
+        Filter::Invert(100) => stream.push_str("invert"),
+        Filter::Invert(x) if x != 0 => write!(stream, "invert-[{}%]", x)?,
+        Filter::Sepia(100) => stream.push_str("sepia"),
+        Filter::Sepia(x) if x != 0 => write!(stream, "sepia-[{}%]", x)?,
+        Filter::DropShadow(DropShadow::Base) => stream.push_str("drop-shadow"),
+        filter => filter.write_classname(stream)?,
+    };
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use crate::{Color, StyleError, StyleOptions};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// The spacing values defined as `--spacing-N` tokens, other values are written as multiples
+/// of `--spacing-1`.
+pub const SPACING_SCALE: [i32; 34] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 14, 16, 20, 24, 28, 32, 36, 40, 44, 48, 52, 56, 60,
+    64, 72, 80, 96, 112, 128, 144, 160,
+];
+
+/// Writes the `:root` block defining the design tokens the css references, see
+/// [`StyleOptions::design_tokens`]. The values are written with the plain options.
+pub(crate) fn write_design_tokens<O: StyleOptions>(
+    stream: &mut String,
+    options: &O,
+    css: &str,
+) -> Result<(), StyleError> {
+    // the names of all `var(--name)` and `var(--name, fallback)` references
+    let mut used = HashSet::new();
+    let mut rest = css;
+    while let Some(start) = rest.find("var(--") {
+        rest = &rest[start + 4..];
+        let end = rest.find([')', ',']).unwrap_or(rest.len());
+        used.insert(&rest[..end]);
+        rest = &rest[end..];
+    }
+
+    let mut token = String::with_capacity(32);
+    let mut tokens = 0;
+    let mut declare = |stream: &mut String, token: &str| {
+        let used = used.contains(token);
+        if used {
+            stream.push_str(if tokens == 0 { ":root{" } else { ";" });
+            write!(stream, "{}:", token)?;
+            tokens += 1;
+        }
+        Ok::<bool, StyleError>(used)
+    };
+
+    for color in Color::palette().chain(options.palette().colors()) {
+        token.clear();
+        write!(token, "--color-{}", color.to_classname())?;
+        if declare(stream, &token)? {
+            let value = options
+                .palette()
+                .resolve(&color)
+                .ok_or_else(|| StyleError::InvalidSimpleColor(color.to_classname().to_string()))?;
+            options.color(stream, &value)?;
+        }
+    }
+    for value in SPACING_SCALE {
+        token.clear();
+        write!(token, "--spacing-{}", value)?;
+        if declare(stream, &token)? {
+            options.spacing(stream, value)?;
+        }
+    }
+    if tokens > 0 {
+        stream.push('}');
+    }
+    Ok(())
+}